@@ -0,0 +1,473 @@
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::{BitOr, Sub};
+use unicode_segmentation::{GraphemeIndices, UnicodeSegmentation};
+
+/// A set of word boundaries used when splitting the input into words.
+///
+/// Boundaries are combined with `|` and removed with `-`.
+///
+/// Custom predicates are compared by their address, so the same closure may be
+/// seen as different predicates if it is created in several places.
+///
+/// # Examples
+/// ```
+/// use reword::{Boundary, Case, CaseBuilder};
+///
+/// # #[cfg(feature = "alloc")] {
+/// let case = CaseBuilder::from(Case::Snake)
+///     .boundary(Boundary::DEFAULT - Boundary::DOT - Boundary::DIGIT)
+///     .build();
+/// assert_eq!(case.convert("appVersion version.1.2"), "app_version_version.1.2");
+/// # }
+/// ```
+#[derive(Copy, Clone)]
+pub struct Boundary {
+    bits: u16,
+    custom: [Option<Predicate>; Boundary::MAX_CUSTOM],
+}
+
+/// A custom predicate on the characters that separate words.
+type Predicate = fn(char) -> bool;
+
+impl Boundary {
+    /// No boundaries, the input is a single word.
+    pub const NONE: Boundary = Boundary::from_bits(0);
+    /// `_`
+    pub const UNDERSCORE: Boundary = Boundary::from_bits(1 << 0);
+    /// `-`
+    pub const HYPHEN: Boundary = Boundary::from_bits(1 << 1);
+    /// Whitespace.
+    pub const SPACE: Boundary = Boundary::from_bits(1 << 2);
+    /// `.`
+    pub const DOT: Boundary = Boundary::from_bits(1 << 3);
+    /// `/`
+    pub const SLASH: Boundary = Boundary::from_bits(1 << 4);
+    /// Any other character that is not a letter or a digit, like `(`, `&` or `?`.
+    pub const PUNCTUATION: Boundary = Boundary::from_bits(1 << 5);
+    /// A lowercase letter followed by an uppercase letter, like `helloWorld`.
+    pub const LOWER_UPPER: Boundary = Boundary::from_bits(1 << 6);
+    /// The end of an acronym followed by a capitalized word, like `XMLHttp`.
+    pub const ACRONYM: Boundary = Boundary::from_bits(1 << 7);
+    /// A letter followed by a digit or a digit followed by a letter, like `utf8`.
+    pub const DIGIT: Boundary = Boundary::from_bits(1 << 8);
+    /// All the boundaries above, used by default.
+    pub const DEFAULT: Boundary = Boundary::from_bits((1 << 9) - 1);
+
+    /// The most custom predicates a set can hold.
+    const MAX_CUSTOM: usize = 4;
+
+    const fn from_bits(bits: u16) -> Boundary {
+        Boundary {
+            bits,
+            custom: [None; Boundary::MAX_CUSTOM],
+        }
+    }
+
+    /// Returns a boundary on the characters that matches the predicate.
+    ///
+    /// The predicates of both sides are kept when boundaries are combined, and a
+    /// set can hold at most four different predicates.
+    ///
+    /// # Examples
+    /// ```
+    /// use reword::{Boundary, Case, CaseBuilder};
+    ///
+    /// # #[cfg(feature = "alloc")] {
+    /// let case = CaseBuilder::from(Case::Kebab)
+    ///     .boundary(Boundary::DEFAULT | Boundary::custom(|c| c == 'x'))
+    ///     .build();
+    /// assert_eq!(case.convert("1920x1080"), "1920-1080");
+    /// # }
+    /// ```
+    pub const fn custom(f: fn(char) -> bool) -> Boundary {
+        let mut boundary = Boundary::NONE;
+        boundary.custom[0] = Some(f);
+        boundary
+    }
+
+    /// Returns the boundaries in either set.
+    ///
+    /// # Panics
+    /// Panics if the sets have more than four different custom predicates together,
+    /// see [`Boundary::try_union`].
+    pub fn union(self, other: Boundary) -> Boundary {
+        self.try_union(other)
+            .expect("a boundary can hold at most four custom predicates")
+    }
+
+    /// Returns the boundaries in either set, or `None` if the sets have more than
+    /// four different custom predicates together.
+    pub fn try_union(self, other: Boundary) -> Option<Boundary> {
+        let mut boundary = self;
+        boundary.bits |= other.bits;
+        for f in other.custom.into_iter().flatten() {
+            if boundary.has_custom(f) {
+                continue;
+            }
+            *boundary.custom.iter_mut().find(|g| g.is_none())? = Some(f);
+        }
+        Some(boundary)
+    }
+
+    /// Returns the boundaries in this set that are not in the other set.
+    pub fn difference(self, other: Boundary) -> Boundary {
+        let mut boundary = Boundary::from_bits(self.bits & !other.bits);
+        let kept = self.custom.into_iter().flatten();
+        for (slot, f) in boundary
+            .custom
+            .iter_mut()
+            .zip(kept.filter(|&f| !other.has_custom(f)))
+        {
+            *slot = Some(f);
+        }
+        boundary
+    }
+
+    /// Returns `true` if all the boundaries in the other set are in this set.
+    pub const fn contains(self, other: Boundary) -> bool {
+        self.bits & other.bits == other.bits
+    }
+
+    /// Returns `true` if the set has the custom predicate.
+    fn has_custom(&self, f: Predicate) -> bool {
+        self.custom
+            .iter()
+            .flatten()
+            .any(|&g| core::ptr::fn_addr_eq(f, g))
+    }
+
+    /// Returns `true` if the character separates two words.
+    fn is_separator(self, c: char) -> bool {
+        if self.custom.iter().flatten().any(|f| f(c)) {
+            return true;
+        }
+        let boundary = match c {
+            '_' => Boundary::UNDERSCORE,
+            '-' => Boundary::HYPHEN,
+            '.' => Boundary::DOT,
+            '/' => Boundary::SLASH,
+            c if c.is_whitespace() => Boundary::SPACE,
+            c if c.is_alphanumeric() => return false,
+            _ => Boundary::PUNCTUATION,
+        };
+        self.contains(boundary)
+    }
+}
+
+impl Default for Boundary {
+    fn default() -> Boundary {
+        Boundary::DEFAULT
+    }
+}
+
+impl BitOr for Boundary {
+    type Output = Boundary;
+
+    fn bitor(self, other: Boundary) -> Boundary {
+        self.union(other)
+    }
+}
+
+impl Sub for Boundary {
+    type Output = Boundary;
+
+    fn sub(self, other: Boundary) -> Boundary {
+        self.difference(other)
+    }
+}
+
+impl PartialEq for Boundary {
+    fn eq(&self, other: &Boundary) -> bool {
+        let len = |b: &Boundary| b.custom.iter().flatten().count();
+        self.bits == other.bits
+            && len(self) == len(other)
+            && self.custom.iter().flatten().all(|&f| other.has_custom(f))
+    }
+}
+
+impl Eq for Boundary {}
+
+impl Hash for Boundary {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // The predicates are not in any order, so only their number is hashed.
+        self.bits.hash(state);
+        self.custom.iter().flatten().count().hash(state);
+    }
+}
+
+impl fmt::Debug for Boundary {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        const NAMES: [&str; 9] = [
+            "UNDERSCORE",
+            "HYPHEN",
+            "SPACE",
+            "DOT",
+            "SLASH",
+            "PUNCTUATION",
+            "LOWER_UPPER",
+            "ACRONYM",
+            "DIGIT",
+        ];
+        let mut set = f.debug_set();
+        for (i, name) in NAMES.iter().enumerate() {
+            if self.bits & (1 << i) != 0 {
+                set.entry(&format_args!("{name}"));
+            }
+        }
+        for _ in self.custom.iter().flatten() {
+            set.entry(&format_args!("custom"));
+        }
+        set.finish()
+    }
+}
+
+/// Splits the input into words on the boundaries, and splits runs of uppercase
+/// letters into the acronyms.
+pub(crate) fn words<'a>(t: &'a str, boundary: Boundary, acronyms: &'a [&'a str]) -> Words<'a> {
+    Words {
+        t,
+        graphemes: t.grapheme_indices(true),
+        boundary,
+        identifier: (0, false),
+        segments: Segments {
+            rest: "",
+            boundary,
+            acronyms,
+        },
+    }
+}
+
+/// The words of the input, see [`words`].
+#[derive(Clone)]
+pub(crate) struct Words<'a> {
+    t: &'a str,
+    graphemes: GraphemeIndices<'a>,
+    boundary: Boundary,
+    /// The end of the current identifier, and if its casing looks random.
+    identifier: (usize, bool),
+    segments: Segments<'a>,
+}
+
+impl<'a> Words<'a> {
+    /// Returns the start and the next part of the input between two separators.
+    fn next_part(&mut self) -> Option<(usize, &'a str)> {
+        let boundary = self.boundary;
+        let is_separator = |g: &str| g.chars().next().is_some_and(|c| boundary.is_separator(c));
+        let (start, _) = self.graphemes.find(|(_, g)| !is_separator(g))?;
+        let end = self
+            .graphemes
+            .find(|(_, g)| is_separator(g))
+            .map_or(self.t.len(), |(i, _)| i);
+        Some((start, &self.t[start..end]))
+    }
+
+    /// Returns `true` if the casing of the identifier the part at `start` is in
+    /// looks random.
+    ///
+    /// An identifier is the input between two whitespaces, so `iNpUt_wiTh` is
+    /// one identifier where `wiTh` has random casing too.
+    fn is_mixed_case(&mut self, start: usize, part: &str) -> bool {
+        let (end, mixed) = self.identifier;
+        if start < end {
+            return mixed;
+        }
+        let end = self.t[start..]
+            .find(char::is_whitespace)
+            .map_or(self.t.len(), |i| start + i)
+            .max(start + part.len());
+        self.identifier = (end, is_mixed_case(&self.t[start..end]));
+        self.identifier.1
+    }
+}
+
+impl<'a> Iterator for Words<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        if let Some(word) = self.segments.next() {
+            return Some(word);
+        }
+        let (start, part) = self.next_part()?;
+        self.segments.rest = part;
+        self.segments.boundary = if self.is_mixed_case(start, part) {
+            self.boundary - Boundary::LOWER_UPPER - Boundary::ACRONYM
+        } else {
+            self.boundary
+        };
+        self.segments.next()
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+enum Kind {
+    Upper,
+    Lower,
+    Caseless,
+    Digit,
+}
+
+impl Kind {
+    fn of(c: char) -> Option<Kind> {
+        if c.is_uppercase() {
+            Some(Kind::Upper)
+        } else if c.is_lowercase() {
+            Some(Kind::Lower)
+        } else if c.is_numeric() {
+            Some(Kind::Digit)
+        } else if c.is_alphabetic() {
+            Some(Kind::Caseless)
+        } else {
+            None
+        }
+    }
+}
+
+/// Returns `true` if the casing of an identifier looks random, like `iNpUt`.
+///
+/// Case transitions are not used as word boundaries in such an identifier. Four
+/// single letter runs of alternating case occurs in camel case, like `aToB`, so it
+/// takes five or more in a row.
+fn is_mixed_case(t: &str) -> bool {
+    let mut singles = 0;
+    let mut run: Option<(Kind, usize)> = None;
+    for c in t.chars() {
+        let kind = Kind::of(c).filter(|&k| k == Kind::Upper || k == Kind::Lower);
+        match (run, kind) {
+            (Some((prev, len)), Some(kind)) if prev == kind => run = Some((prev, len + 1)),
+            (Some((_, len)), kind) => {
+                singles = if len == 1 { singles + 1 } else { 0 };
+                if singles >= 5 {
+                    return true;
+                }
+                if kind.is_none() {
+                    singles = 0;
+                }
+                run = kind.map(|k| (k, 1));
+            }
+            (None, kind) => run = kind.map(|k| (k, 1)),
+        }
+    }
+    matches!(run, Some((_, 1))) && singles >= 4
+}
+
+/// Splits a word on case and digit boundaries.
+#[derive(Clone)]
+struct Segments<'a> {
+    rest: &'a str,
+    boundary: Boundary,
+    acronyms: &'a [&'a str],
+}
+
+impl<'a> Segments<'a> {
+    /// Returns the byte index of the end of the first acronym in the uppercase
+    /// run at the start of the rest of the word, if the run is made up of acronyms.
+    ///
+    /// The last letter of a run followed by a lowercase letter starts the next
+    /// word, like the `R` in `JSONAPIResponse`.
+    fn acronym(&self) -> Option<usize> {
+        let mut run = self
+            .rest
+            .char_indices()
+            .skip_while(|&(_, c)| c.is_uppercase());
+        let end = match run.next() {
+            Some((i, c)) if c.is_lowercase() => self.rest[..i].char_indices().next_back()?.0,
+            Some((i, _)) => i,
+            None => self.rest.len(),
+        };
+        let first = split_acronyms(&self.rest[..end], self.acronyms)?;
+        (first < end).then_some(first)
+    }
+
+    /// Returns the byte index of the first boundary in the rest of the word.
+    fn boundary(&self) -> usize {
+        if self.boundary.contains(Boundary::ACRONYM)
+            && let Some(i) = self.acronym()
+        {
+            return i;
+        }
+        let lower_upper = self.boundary.contains(Boundary::LOWER_UPPER);
+        let acronym = self.boundary.contains(Boundary::ACRONYM);
+        let digit = self.boundary.contains(Boundary::DIGIT);
+        let mut prev: Option<(usize, Kind)> = None;
+        let mut before_prev = None;
+        for (i, c) in self.rest.char_indices() {
+            // Combining marks and other symbols belong to the preceding letter.
+            let Some(kind) = Kind::of(c) else {
+                continue;
+            };
+            if let Some((j, p)) = prev {
+                if digit && (p == Kind::Digit) != (kind == Kind::Digit) {
+                    return i;
+                }
+                if lower_upper && p == Kind::Lower && kind == Kind::Upper {
+                    return i;
+                }
+                if acronym
+                    && before_prev == Some(Kind::Upper)
+                    && p == Kind::Upper
+                    && kind == Kind::Lower
+                {
+                    return j;
+                }
+            }
+            before_prev = prev.map(|(_, p)| p);
+            prev = Some((i, kind));
+        }
+        self.rest.len()
+    }
+}
+
+impl<'a> Iterator for Segments<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        if self.rest.is_empty() {
+            return None;
+        }
+        let (word, rest) = self.rest.split_at(self.boundary());
+        self.rest = rest;
+        Some(word)
+    }
+}
+
+/// Returns the length of the first acronym if the run can be split into acronyms,
+/// preferring the longest ones.
+///
+/// The run is walked backwards once, keeping track of which of the following
+/// positions starts a run of acronyms, so acronyms of more than 127 letters are
+/// never used.
+fn split_acronyms(run: &str, acronyms: &[&str]) -> Option<usize> {
+    // Bit `n` is set if the run `n` letters ahead can be split into acronyms.
+    let mut fits: u128 = 1;
+    let mut first = None;
+    for (i, _) in run.char_indices().rev() {
+        fits <<= 1;
+        first = None;
+        for a in acronyms {
+            let Some(rest) = strip_prefix_ignore_case(&run[i..], a) else {
+                continue;
+            };
+            let letters = a.chars().count();
+            if letters < 128 && fits & (1 << letters) != 0 {
+                fits |= 1;
+                let len = run.len() - i - rest.len();
+                first = first.max(Some(len));
+            }
+        }
+    }
+    first
+}
+
+/// Removes a non-empty prefix from the start of the string, ignoring the case.
+fn strip_prefix_ignore_case<'a>(t: &'a str, prefix: &str) -> Option<&'a str> {
+    let mut chars = t.char_indices();
+    for p in prefix.chars() {
+        let (_, c) = chars.next()?;
+        if !c.to_lowercase().eq(p.to_lowercase()) {
+            return None;
+        }
+    }
+    let end = chars.next().map_or(t.len(), |(i, _)| i);
+    (!prefix.is_empty()).then(|| &t[end..])
+}
@@ -0,0 +1,41 @@
+use core::fmt;
+
+/// The error returned when the output does not fit in the provided buffer.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct CapacityError(());
+
+impl fmt::Display for CapacityError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("buffer is too small")
+    }
+}
+
+impl core::error::Error for CapacityError {}
+
+/// Writes into a fixed size buffer.
+struct Buffer<'a> {
+    buf: &'a mut [u8],
+    len: usize,
+}
+
+impl fmt::Write for Buffer<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        let dst = self.buf.get_mut(self.len..end).ok_or(fmt::Error)?;
+        dst.copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+/// Calls `f` with a writer into the buffer and returns what was written.
+pub(crate) fn write_into<F>(buf: &mut [u8], f: F) -> Result<&str, CapacityError>
+where
+    F: FnOnce(&mut dyn fmt::Write) -> fmt::Result,
+{
+    let mut buffer = Buffer { buf, len: 0 };
+    f(&mut buffer).map_err(|_| CapacityError(()))?;
+    let Buffer { buf, len } = buffer;
+    // Only whole strings are written to the buffer.
+    Ok(core::str::from_utf8(&buf[..len]).expect("buffer contains valid utf-8"))
+}
@@ -1,8 +1,1042 @@
-use crate::{fold, name, name_with_limit};
+use crate::{Boundary, CapacityError, Locale, Unit, display_eq, is_above, pad, words, write_into};
+#[cfg(feature = "alloc")]
+use crate::{cow, shorten};
+#[cfg(feature = "alloc")]
+use alloc::borrow::Cow;
+#[cfg(feature = "alloc")]
 use alloc::string::String;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::fmt;
+use core::str::FromStr;
 
-fn pat(c: char) -> bool {
-    c.is_ascii_punctuation() || c.is_whitespace()
+/// A naming convention that the input string can be converted into.
+///
+/// The name of each case is formatted in its own convention, and parsing accepts
+/// the name in any convention, with or without the `case` suffix. `COBOL-CASE`
+/// is parsed as [`Case::ScreamingKebab`], since the two are the same.
+///
+/// # Examples
+/// ```
+/// use reword::Case;
+///
+/// let case: Case = "kebab-case".parse().unwrap();
+/// assert_eq!(case, Case::Kebab);
+/// assert_eq!("SCREAMING_SNAKE_CASE".parse(), Ok(Case::ScreamingSnake));
+/// assert_eq!("pascal".parse(), Ok(Case::Pascal));
+/// assert_eq!("COBOL-CASE".parse(), Ok(Case::ScreamingKebab));
+/// assert_eq!(Case::Camel.to_string(), "camelCase");
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum Case {
+    /// `snake_case`
+    Snake,
+    /// `SCREAMING_SNAKE_CASE`
+    ScreamingSnake,
+    /// `kebab-case`
+    Kebab,
+    /// `SCREAMING-KEBAB-CASE`
+    ScreamingKebab,
+    /// `camelCase`
+    Camel,
+    /// `PascalCase`
+    Pascal,
+    /// `Title Case`, using the default [`TitleStyle`].
+    Title,
+    /// `Sentence case`
+    Sentence,
+    /// `Train-Case`
+    Train,
+    /// `dot.case`
+    Dot,
+    /// `path/case`
+    Path,
+    /// `flatcase`
+    Flat,
+    /// `UPPERFLATCASE`
+    UpperFlat,
+    /// `Ada_Case`
+    Ada,
+}
+
+impl Case {
+    /// All the cases.
+    pub const ALL: &'static [Case] = &[
+        Case::Snake,
+        Case::ScreamingSnake,
+        Case::Kebab,
+        Case::ScreamingKebab,
+        Case::Camel,
+        Case::Pascal,
+        Case::Title,
+        Case::Sentence,
+        Case::Train,
+        Case::Dot,
+        Case::Path,
+        Case::Flat,
+        Case::UpperFlat,
+        Case::Ada,
+    ];
+
+    /// Returns the name of the case, formatted in its own convention.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Case::Snake => "snake_case",
+            Case::ScreamingSnake => "SCREAMING_SNAKE_CASE",
+            Case::Kebab => "kebab-case",
+            Case::ScreamingKebab => "SCREAMING-KEBAB-CASE",
+            Case::Camel => "camelCase",
+            Case::Pascal => "PascalCase",
+            Case::Title => "Title Case",
+            Case::Sentence => "Sentence case",
+            Case::Train => "Train-Case",
+            Case::Dot => "dot.case",
+            Case::Path => "path/case",
+            Case::Flat => "flatcase",
+            Case::UpperFlat => "UPPERFLATCASE",
+            Case::Ada => "Ada_Case",
+        }
+    }
+
+    /// Returns a builder with the settings of this case.
+    const fn builder(self) -> CaseBuilder<'static> {
+        let (separator, first, rest) = match self {
+            Case::Snake => ("_", WordCase::Lower, WordCase::Lower),
+            Case::ScreamingSnake => ("_", WordCase::Upper, WordCase::Upper),
+            Case::Kebab => ("-", WordCase::Lower, WordCase::Lower),
+            Case::ScreamingKebab => ("-", WordCase::Upper, WordCase::Upper),
+            Case::Camel => ("", WordCase::Lower, WordCase::Capitalized),
+            Case::Pascal => ("", WordCase::Capitalized, WordCase::Capitalized),
+            Case::Title => (" ", WordCase::Capitalized, WordCase::Capitalized),
+            Case::Sentence => (" ", WordCase::Capitalized, WordCase::Lower),
+            Case::Train => ("-", WordCase::Capitalized, WordCase::Capitalized),
+            Case::Dot => (".", WordCase::Lower, WordCase::Lower),
+            Case::Path => ("/", WordCase::Lower, WordCase::Lower),
+            Case::Flat => ("", WordCase::Lower, WordCase::Lower),
+            Case::UpperFlat => ("", WordCase::Upper, WordCase::Upper),
+            Case::Ada => ("_", WordCase::Capitalized, WordCase::Capitalized),
+        };
+        let builder = CaseBuilder::new()
+            .separator(separator)
+            .first_word_case(first)
+            .word_case(rest);
+        match self {
+            Case::Camel | Case::Pascal => builder.digit_separator("_"),
+            Case::Title => builder.title_style(TitleStyle::Chicago),
+            _ => builder,
+        }
+    }
+}
+
+impl fmt::Display for Case {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(self.name())
+    }
+}
+
+impl FromStr for Case {
+    type Err = ParseCaseError;
+
+    fn from_str(s: &str) -> Result<Case, ParseCaseError> {
+        // Longer than any of the names.
+        let mut buf = [0; 32];
+        let name = convert_into(s, Case::Flat, &mut buf).map_err(|_| ParseCaseError(()))?;
+        let name = name.strip_suffix("case").unwrap_or(name);
+        if name == "cobol" {
+            return Ok(Case::ScreamingKebab);
+        }
+        Case::ALL
+            .iter()
+            .copied()
+            .find(|case| {
+                let mut buf = [0; 32];
+                let other = convert_into(case.name(), Case::Flat, &mut buf).unwrap_or_default();
+                name == other.strip_suffix("case").unwrap_or(other)
+            })
+            .ok_or(ParseCaseError(()))
+    }
+}
+
+/// The error returned when parsing an unknown [`Case`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseCaseError(());
+
+impl fmt::Display for ParseCaseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("unknown case")
+    }
+}
+
+impl core::error::Error for ParseCaseError {}
+
+/// The style guide used to decide which words are lowercased in title case.
+///
+/// The first and last words are always capitalized, and words in all caps, like
+/// `NASA`, are kept as is unless the whole input is in all caps. Minor words are
+/// lowercased even in all caps.
+///
+/// # Examples
+/// ```
+/// use reword::TitleStyle;
+///
+/// # #[cfg(feature = "alloc")] {
+/// let t = "a guide to writing from start to finish";
+/// assert_eq!(reword::title_case_with_style(t, TitleStyle::Ap), "A Guide to Writing From Start to Finish");
+/// assert_eq!(reword::title_case_with_style(t, TitleStyle::Chicago), "A Guide to Writing from Start to Finish");
+/// assert_eq!(reword::title_case_with_style(t, TitleStyle::All), "A Guide To Writing From Start To Finish");
+/// # }
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum TitleStyle {
+    /// Associated Press: articles, coordinating conjunctions and prepositions of
+    /// three letters or fewer are lowercased.
+    Ap,
+    /// Chicago Manual of Style: articles, coordinating conjunctions and all
+    /// prepositions are lowercased.
+    #[default]
+    Chicago,
+    /// American Psychological Association: articles, conjunctions and prepositions
+    /// of three letters or fewer are lowercased.
+    Apa,
+    /// Every word is capitalized.
+    All,
+}
+
+impl TitleStyle {
+    /// Returns `true` if the word should be lowercased when not first or last.
+    fn is_minor(self, word: &str) -> bool {
+        const ARTICLES: &[&str] = &["a", "an", "the"];
+        const CONJUNCTIONS: &[&str] = &["and", "but", "for", "nor", "or", "so", "yet"];
+        // Chicago capitalizes `so` and `yet`.
+        const CHICAGO_CONJUNCTIONS: &[&str] = &["and", "but", "for", "nor", "or"];
+        const SHORT_PREPOSITIONS: &[&str] = &[
+            "as", "at", "by", "for", "in", "of", "off", "on", "out", "per", "to", "up", "via",
+        ];
+        const PREPOSITIONS: &[&str] = &[
+            "about",
+            "above",
+            "across",
+            "after",
+            "against",
+            "along",
+            "among",
+            "around",
+            "before",
+            "behind",
+            "below",
+            "beneath",
+            "beside",
+            "between",
+            "beyond",
+            "despite",
+            "down",
+            "during",
+            "except",
+            "from",
+            "inside",
+            "into",
+            "like",
+            "near",
+            "onto",
+            "outside",
+            "over",
+            "past",
+            "since",
+            "through",
+            "throughout",
+            "toward",
+            "towards",
+            "under",
+            "underneath",
+            "until",
+            "upon",
+            "with",
+            "within",
+            "without",
+        ];
+
+        let is = |words: &[&str]| words.iter().any(|w| w.eq_ignore_ascii_case(word));
+        match self {
+            TitleStyle::Ap => is(ARTICLES) || is(CONJUNCTIONS) || is(SHORT_PREPOSITIONS),
+            TitleStyle::Chicago => {
+                is(ARTICLES)
+                    || is(CHICAGO_CONJUNCTIONS)
+                    || is(SHORT_PREPOSITIONS)
+                    || is(PREPOSITIONS)
+            }
+            TitleStyle::Apa => {
+                word.chars().count() <= 3
+                    && (is(ARTICLES) || is(CONJUNCTIONS) || is(SHORT_PREPOSITIONS) || is(&["if"]))
+            }
+            TitleStyle::All => false,
+        }
+    }
+}
+
+fn is_acronym(word: &str) -> bool {
+    word.chars().nth(1).is_some() && !word.chars().any(char::is_lowercase)
+}
+
+/// The casing applied to a single word.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum WordCase {
+    /// `word`
+    #[default]
+    Lower,
+    /// `WORD`
+    Upper,
+    /// `Word`
+    Capitalized,
+    /// The word is kept as is.
+    Preserve,
+}
+
+impl WordCase {
+    fn write<W: fmt::Write>(self, w: &mut W, word: &str, locale: Locale) -> fmt::Result {
+        for (i, c) in word.char_indices() {
+            let (before, after) = (&word[..i], &word[i + c.len_utf8()..]);
+            match self {
+                WordCase::Preserve => return w.write_str(word),
+                WordCase::Capitalized if i == 0 => locale.write_title(w, c, before)?,
+                // The marks above the first letter are uppercased with it.
+                WordCase::Capitalized if is_above(c) && before.chars().skip(1).all(is_above) => {
+                    locale.write_upper(w, c, before)?
+                }
+                WordCase::Upper => locale.write_upper(w, c, before)?,
+                _ => locale.write_lower(w, c, before, after)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A default set of acronyms for use with [`CaseBuilder::acronyms`].
+pub const ACRONYMS: &[&str] = &[
+    "ACL", "API", "ASCII", "CPU", "CSS", "CSV", "DNS", "EOF", "GUID", "HTML", "HTTP", "HTTPS",
+    "ID", "IO", "IP", "JSON", "JWT", "OS", "RAM", "RPC", "SDK", "SQL", "SSH", "SSL", "TCP", "TLS",
+    "TTL", "UDP", "UI", "UID", "URI", "URL", "UUID", "VM", "XML", "XSRF", "XSS", "YAML",
+];
+
+/// Builds a [`CustomCase`] for naming conventions that are not a [`Case`].
+///
+/// A builder can also be created from a [`Case`], to change how the input is
+/// split into words.
+///
+/// # Examples
+/// ```
+/// use reword::{CaseBuilder, WordCase};
+///
+/// # #[cfg(feature = "alloc")] {
+/// let case = CaseBuilder::new()
+///     .separator(".")
+///     .first_word_case(WordCase::Lower)
+///     .word_case(WordCase::Capitalized)
+///     .build();
+/// assert_eq!(case.convert("hello big world"), "hello.Big.World");
+/// # }
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct CaseBuilder<'a> {
+    separator: &'a str,
+    first: Option<WordCase>,
+    rest: WordCase,
+    digit_separator: Option<&'a str>,
+    title: Option<TitleStyle>,
+    boundary: Boundary,
+    acronyms: &'a [&'a str],
+    locale: Locale,
+    unit: Unit,
+}
+
+impl<'a> CaseBuilder<'a> {
+    /// Returns a builder for a case with no separator and lowercase words.
+    pub const fn new() -> CaseBuilder<'a> {
+        CaseBuilder {
+            separator: "",
+            first: None,
+            rest: WordCase::Lower,
+            digit_separator: None,
+            title: None,
+            boundary: Boundary::DEFAULT,
+            acronyms: &[],
+            locale: Locale::Root,
+            unit: Unit::Graphemes,
+        }
+    }
+
+    /// Sets the separator between words, which can be empty.
+    pub const fn separator(mut self, separator: &'a str) -> CaseBuilder<'a> {
+        self.separator = separator;
+        self
+    }
+
+    /// Sets the casing of the words.
+    ///
+    /// This also applies to the first word, unless [`first_word_case`](CaseBuilder::first_word_case) is set.
+    pub const fn word_case(mut self, case: WordCase) -> CaseBuilder<'a> {
+        self.rest = case;
+        self
+    }
+
+    /// Sets the casing of the first word.
+    pub const fn first_word_case(mut self, case: WordCase) -> CaseBuilder<'a> {
+        self.first = Some(case);
+        self
+    }
+
+    /// Sets the separator used instead of the word separator when a word ending
+    /// in a digit is followed by a word starting with a digit, like the `_` in
+    /// `version1_2` in camel case.
+    pub const fn digit_separator(mut self, separator: &'a str) -> CaseBuilder<'a> {
+        self.digit_separator = Some(separator);
+        self
+    }
+
+    /// Lowercases minor words that are not first or last, and keeps acronyms, as
+    /// described by the style.
+    pub const fn title_style(mut self, style: TitleStyle) -> CaseBuilder<'a> {
+        self.title = Some(style);
+        self
+    }
+
+    /// Sets the boundaries used to split the input into words.
+    ///
+    /// Defaults to [`Boundary::DEFAULT`].
+    pub const fn boundary(mut self, boundary: Boundary) -> CaseBuilder<'a> {
+        self.boundary = boundary;
+        self
+    }
+
+    /// Sets the acronyms that are kept in all caps in capitalized words, like
+    /// `UserID` instead of `UserId`.
+    ///
+    /// Acronyms are matched without regard to case, and are lowercased as usual
+    /// when the word is lowercased. See [`ACRONYMS`] for a default set.
+    ///
+    /// # Examples
+    /// ```
+    /// use reword::{Case, CaseBuilder};
+    ///
+    /// # #[cfg(feature = "alloc")] {
+    /// let case = CaseBuilder::from(Case::Camel).acronyms(reword::ACRONYMS).build();
+    /// assert_eq!(case.convert("JSON_API_response"), "jsonAPIResponse");
+    /// assert_eq!(case.convert("user_id"), "userID");
+    /// # }
+    /// ```
+    pub const fn acronyms(mut self, acronyms: &'a [&'a str]) -> CaseBuilder<'a> {
+        self.acronyms = acronyms;
+        self
+    }
+
+    /// Sets the language specific case mappings that are used.
+    ///
+    /// Defaults to [`Locale::Root`].
+    ///
+    /// # Examples
+    /// ```
+    /// use reword::{Case, CaseBuilder, Locale};
+    ///
+    /// # #[cfg(feature = "alloc")] {
+    /// let case = CaseBuilder::from(Case::Pascal).locale(Locale::Turkish).build();
+    /// assert_eq!(case.convert("izmir il"), "İzmirİl");
+    /// # }
+    /// ```
+    pub const fn locale(mut self, locale: Locale) -> CaseBuilder<'a> {
+        self.locale = locale;
+        self
+    }
+
+    /// Sets the unit used to measure the length of the name when it is limited.
+    ///
+    /// Defaults to [`Unit::Graphemes`].
+    ///
+    /// # Examples
+    /// ```
+    /// use reword::{Case, CaseBuilder, Unit};
+    ///
+    /// # #[cfg(feature = "alloc")] {
+    /// let case = CaseBuilder::from(Case::Snake).limit_unit(Unit::Bytes).build();
+    /// assert_eq!(case.convert_with_limit("blåbær syltetøy", 16), "blåbær_s");
+    /// # }
+    /// ```
+    pub const fn limit_unit(mut self, unit: Unit) -> CaseBuilder<'a> {
+        self.unit = unit;
+        self
+    }
+
+    /// Builds the case.
+    pub const fn build(self) -> CustomCase<'a> {
+        CustomCase {
+            separator: self.separator,
+            first: match self.first {
+                Some(first) => first,
+                None => self.rest,
+            },
+            rest: self.rest,
+            digit_separator: self.digit_separator,
+            title: self.title,
+            boundary: self.boundary,
+            acronyms: self.acronyms,
+            locale: self.locale,
+            unit: self.unit,
+        }
+    }
+}
+
+impl From<Case> for CaseBuilder<'_> {
+    fn from(case: Case) -> Self {
+        case.builder()
+    }
+}
+
+/// A user defined naming convention, created by a [`CaseBuilder`].
+///
+/// # Examples
+/// ```
+/// use reword::{CaseBuilder, WordCase};
+///
+/// # #[cfg(feature = "alloc")] {
+/// let case = CaseBuilder::new()
+///     .separator("_")
+///     .word_case(WordCase::Capitalized)
+///     .first_word_case(WordCase::Lower)
+///     .build();
+/// assert_eq!(case.convert("Even Olsson Rogstadkjærnet"), "even_Olsson_Rogstadkjærnet");
+/// assert_eq!(case.convert_with_limit("Even Olsson Rogstadkjærnet", 25), "even_O_Rogstadkjærnet");
+/// # }
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct CustomCase<'a> {
+    separator: &'a str,
+    first: WordCase,
+    rest: WordCase,
+    digit_separator: Option<&'a str>,
+    title: Option<TitleStyle>,
+    boundary: Boundary,
+    acronyms: &'a [&'a str],
+    locale: Locale,
+    unit: Unit,
+}
+
+impl CustomCase<'_> {
+    /// Formats the input string in this case.
+    #[cfg(feature = "alloc")]
+    pub fn convert<T: AsRef<str>>(&self, t: T) -> String {
+        to_string(|s| self.write(s, t))
+    }
+
+    /// Formats the input string in this case and limits the length of the name.
+    #[cfg(feature = "alloc")]
+    pub fn convert_with_limit<T: AsRef<str>>(&self, t: T, limit: usize) -> String {
+        to_string(|s| self.write_with_limit(s, t, limit))
+    }
+
+    /// Formats the input string in this case into the buffer.
+    pub fn convert_into<'b, T: AsRef<str>>(
+        &self,
+        t: T,
+        buf: &'b mut [u8],
+    ) -> Result<&'b str, CapacityError> {
+        write_into(buf, |mut w| self.write(&mut w, t))
+    }
+
+    /// Writes the input string in this case to the writer, without allocating.
+    pub fn write<W: fmt::Write, T: AsRef<str>>(&self, w: &mut W, t: T) -> fmt::Result {
+        self.join(w, self.cases(t.as_ref()))
+    }
+
+    /// Writes the input string in this case to the writer and limits the length of the name.
+    #[cfg(feature = "alloc")]
+    pub fn write_with_limit<W: fmt::Write, T: AsRef<str>>(
+        &self,
+        w: &mut W,
+        t: T,
+        limit: usize,
+    ) -> fmt::Result {
+        // The words are shortened after the casing is applied, since it can change their length.
+        let cased: Vec<String> = self
+            .cases(t.as_ref())
+            .map(|(word, case)| to_string(|s| case.write(s, word, self.locale)))
+            .collect();
+        let mut words: Vec<&str> = cased.iter().map(String::as_str).collect();
+        let separators = words
+            .windows(2)
+            .map(|pair| self.unit.len(self.separator_between(pair[0], pair[1])))
+            .sum();
+        let name = if shorten(&mut words, limit, self.unit, separators) {
+            to_string(|s| self.join(s, words.into_iter().map(|w| (w, WordCase::Preserve))))
+        } else {
+            to_string(|s| self.first.write(s, &words.concat(), self.locale))
+        };
+        // Shortening the words is not always enough, like when a word is a single
+        // grapheme of several bytes, so the name is cut at the limit as a last resort.
+        w.write_str(self.unit.truncate(&name, limit))
+    }
+
+    fn is_acronym(&self, word: &str) -> bool {
+        let word = word.chars().flat_map(char::to_lowercase);
+        self.acronyms
+            .iter()
+            .any(|a| a.chars().flat_map(char::to_lowercase).eq(word.clone()))
+    }
+
+    /// Returns `true` if the words in all caps are acronyms that are kept in a title.
+    ///
+    /// They are not kept if the whole input is in all caps, unless it can be a
+    /// title already, like `A NASA`, which has a single letter word and no minor
+    /// words in all caps.
+    fn keeps_acronyms(&self, t: &str) -> bool {
+        let Some(style) = self.title else {
+            return false;
+        };
+        if t.chars().any(char::is_lowercase) {
+            return true;
+        }
+        let words = words(t, self.boundary, self.acronyms);
+        let len = words.clone().count();
+        let has_letter = words.clone().any(|w| w.chars().nth(1).is_none());
+        let has_minor = words
+            .enumerate()
+            .any(|(i, w)| i != 0 && i + 1 != len && style.is_minor(w));
+        has_letter && !has_minor
+    }
+
+    /// Returns the words of the input with the casing that is applied to each of them.
+    fn cases<'a>(&'a self, t: &'a str) -> impl Iterator<Item = (&'a str, WordCase)> {
+        let keep_acronyms = self.keeps_acronyms(t);
+        let mut words = words(t, self.boundary, self.acronyms)
+            .enumerate()
+            .peekable();
+        core::iter::from_fn(move || {
+            let (i, word) = words.next()?;
+            let first = i == 0;
+            let last = words.peek().is_none();
+            let case = if first { self.first } else { self.rest };
+            let case = match self.title {
+                Some(style) if !first && !last && style.is_minor(word) => WordCase::Lower,
+                Some(_) if keep_acronyms && is_acronym(word) => WordCase::Preserve,
+                _ if case == WordCase::Capitalized && self.is_acronym(word) => WordCase::Upper,
+                _ => case,
+            };
+            Some((word, case))
+        })
+    }
+
+    /// Returns the separator that is written between two words.
+    fn separator_between(&self, prev: &str, word: &str) -> &str {
+        let end_is_num = matches!(prev.chars().last(), Some(c) if c.is_numeric());
+        let start_is_num = matches!(word.chars().next(), Some(c) if c.is_numeric());
+        match self.digit_separator {
+            Some(sep) if end_is_num && start_is_num => sep,
+            _ => self.separator,
+        }
+    }
+
+    fn join<'a, W, I>(&self, w: &mut W, words: I) -> fmt::Result
+    where
+        W: fmt::Write,
+        I: Iterator<Item = (&'a str, WordCase)>,
+    {
+        let mut prev: Option<&str> = None;
+        for (word, case) in words {
+            if let Some(prev) = prev {
+                w.write_str(self.separator_between(prev, word))?;
+            }
+            case.write(w, word, self.locale)?;
+            prev = Some(word);
+        }
+        Ok(())
+    }
+}
+
+/// Collects the output of a write into a string.
+#[cfg(feature = "alloc")]
+fn to_string(f: impl FnOnce(&mut String) -> fmt::Result) -> String {
+    let mut s = String::new();
+    f(&mut s).expect("writing to a string can not fail");
+    s
+}
+
+/// Formats the input string in the provided case.
+///
+/// # Examples
+/// ```
+/// use reword::Case;
+///
+/// assert_eq!(reword::convert("Even Olsson Rogstadkjærnet", Case::Snake), "even_olsson_rogstadkjærnet");
+/// assert_eq!(reword::convert("XMLHttpRequest", Case::Kebab), "xml-http-request");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn convert<T: AsRef<str>>(t: T, case: Case) -> String {
+    case.builder().build().convert(t)
+}
+
+/// Formats the input string in the provided case, using the case mappings of the locale.
+///
+/// # Examples
+/// ```
+/// use reword::{Case, Locale};
+///
+/// assert_eq!(reword::convert_with_locale("istanbul", Case::Title, Locale::Turkish), "İstanbul");
+/// assert_eq!(reword::convert_with_locale("istanbul", Case::Title, Locale::Root), "Istanbul");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn convert_with_locale<T: AsRef<str>>(t: T, case: Case, locale: Locale) -> String {
+    case.builder().locale(locale).build().convert(t)
+}
+
+/// Formats the input string in the provided case and limits the length of the name.
+///
+/// # Examples
+/// ```
+/// use reword::Case;
+///
+/// let t = "Even Olsson Rogstadkjærnet";
+/// assert_eq!(reword::convert_with_limit(t, Case::Pascal, 20), "EvenORogstadkjærnet");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn convert_with_limit<T: AsRef<str>>(t: T, case: Case, limit: usize) -> String {
+    case.builder().build().convert_with_limit(t, limit)
+}
+
+/// Formats the input string in the provided case and limits the length of the name,
+/// measured in the provided unit.
+///
+/// # Examples
+/// ```
+/// use reword::{Case, Unit};
+///
+/// let t = "Even Olsson Rogstadkjærnet";
+/// assert_eq!(reword::convert_with_limit_unit(t, Case::Kebab, 21, Unit::Chars), "even-o-rogstadkjærnet");
+/// assert_eq!(reword::convert_with_limit_unit(t, Case::Kebab, 21, Unit::Bytes), "even-o-r");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn convert_with_limit_unit<T: AsRef<str>>(
+    t: T,
+    case: Case,
+    limit: usize,
+    unit: Unit,
+) -> String {
+    case.builder()
+        .limit_unit(unit)
+        .build()
+        .convert_with_limit(t, limit)
+}
+
+/// Formats the input string in the provided case, borrowing it if it is already in the case.
+///
+/// # Examples
+/// ```
+/// use std::borrow::Cow;
+/// use reword::Case;
+///
+/// assert!(matches!(reword::convert_cow("user_id", Case::Snake), Cow::Borrowed("user_id")));
+/// assert!(matches!(reword::convert_cow("userId", Case::Snake), Cow::Owned(_)));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn convert_cow(t: &str, case: Case) -> Cow<'_, str> {
+    cow(t, AsCase(t, case))
+}
+
+/// Formats the input string in the provided case into the buffer, without allocating.
+///
+/// # Examples
+/// ```
+/// use reword::Case;
+///
+/// let mut buf = [0; 16];
+/// assert_eq!(reword::convert_into("userId", Case::Snake, &mut buf), Ok("user_id"));
+/// assert!(reword::convert_into("a very long identifier", Case::Snake, &mut buf).is_err());
+/// ```
+pub fn convert_into<T: AsRef<str>>(
+    t: T,
+    case: Case,
+    buf: &mut [u8],
+) -> Result<&str, CapacityError> {
+    case.builder().build().convert_into(t, buf)
+}
+
+/// Writes the input string in the provided case to the writer, without allocating.
+///
+/// # Examples
+/// ```
+/// use core::fmt::Write;
+/// use reword::Case;
+///
+/// let mut s = String::from("let ");
+/// reword::write_case(&mut s, "userId", Case::Snake).unwrap();
+/// s.write_str(" = 1;").unwrap();
+/// assert_eq!(s, "let user_id = 1;");
+/// ```
+pub fn write_case<W: fmt::Write, T: AsRef<str>>(w: &mut W, t: T, case: Case) -> fmt::Result {
+    case.builder().build().write(w, t)
+}
+
+/// Writes the input string in the provided case to the writer and limits the length of the name.
+///
+/// # Examples
+/// ```
+/// use reword::Case;
+///
+/// let mut s = String::new();
+/// reword::write_case_with_limit(&mut s, "Even Olsson Rogstadkjærnet", Case::Kebab, 25).unwrap();
+/// assert_eq!(s, "even-o-rogstadkjærnet");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn write_case_with_limit<W: fmt::Write, T: AsRef<str>>(
+    w: &mut W,
+    t: T,
+    case: Case,
+    limit: usize,
+) -> fmt::Result {
+    case.builder().build().write_with_limit(w, t, limit)
+}
+
+/// Formats the string in the case when displayed, without allocating.
+///
+/// # Examples
+/// ```
+/// use reword::{AsCase, Case};
+///
+/// assert_eq!(format!("{}", AsCase("userId", Case::Kebab)), "user-id");
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct AsCase<T>(pub T, pub Case);
+
+impl<T: AsRef<str>> fmt::Display for AsCase<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        pad(f, |mut w| write_case(&mut w, &self.0, self.1))
+    }
+}
+
+macro_rules! as_case {
+    ($($(#[$attr:meta])* $name:ident => $case:ident,)*) => {$(
+        $(#[$attr])*
+        #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+        pub struct $name<T>(pub T);
+
+        impl<T: AsRef<str>> fmt::Display for $name<T> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                pad(f, |mut w| write_case(&mut w, &self.0, Case::$case))
+            }
+        }
+    )*};
+}
+
+as_case! {
+    /// Formats the string as snake case when displayed, without allocating.
+    ///
+    /// # Examples
+    /// ```
+    /// assert_eq!(format!("{}", reword::AsSnakeCase("user id")), "user_id");
+    /// ```
+    AsSnakeCase => Snake,
+    /// Formats the string as screaming snake case when displayed, without allocating.
+    ///
+    /// # Examples
+    /// ```
+    /// assert_eq!(format!("{}", reword::AsScreamingSnakeCase("user id")), "USER_ID");
+    /// ```
+    AsScreamingSnakeCase => ScreamingSnake,
+    /// Formats the string as kebab case when displayed, without allocating.
+    ///
+    /// # Examples
+    /// ```
+    /// assert_eq!(format!("{}", reword::AsKebabCase("user id")), "user-id");
+    /// ```
+    AsKebabCase => Kebab,
+    /// Formats the string as screaming kebab case when displayed, without allocating.
+    ///
+    /// # Examples
+    /// ```
+    /// assert_eq!(format!("{}", reword::AsScreamingKebabCase("user id")), "USER-ID");
+    /// ```
+    AsScreamingKebabCase => ScreamingKebab,
+    /// Formats the string as camel case when displayed, without allocating.
+    ///
+    /// # Examples
+    /// ```
+    /// assert_eq!(format!("{}", reword::AsCamelCase("user id")), "userId");
+    /// ```
+    AsCamelCase => Camel,
+    /// Formats the string as pascal case when displayed, without allocating.
+    ///
+    /// # Examples
+    /// ```
+    /// assert_eq!(format!("{}", reword::AsPascalCase("user id")), "UserId");
+    /// ```
+    AsPascalCase => Pascal,
+    /// Formats the string as title case when displayed, without allocating.
+    ///
+    /// # Examples
+    /// ```
+    /// assert_eq!(format!("{}", reword::AsTitleCase("user id")), "User Id");
+    /// ```
+    AsTitleCase => Title,
+    /// Formats the string as sentence case when displayed, without allocating.
+    ///
+    /// # Examples
+    /// ```
+    /// assert_eq!(format!("{}", reword::AsSentenceCase("user id")), "User id");
+    /// ```
+    AsSentenceCase => Sentence,
+    /// Formats the string as train case when displayed, without allocating.
+    ///
+    /// # Examples
+    /// ```
+    /// assert_eq!(format!("{}", reword::AsTrainCase("user id")), "User-Id");
+    /// ```
+    AsTrainCase => Train,
+    /// Formats the string as dot case when displayed, without allocating.
+    ///
+    /// # Examples
+    /// ```
+    /// assert_eq!(format!("{}", reword::AsDotCase("user id")), "user.id");
+    /// ```
+    AsDotCase => Dot,
+    /// Formats the string as path case when displayed, without allocating.
+    ///
+    /// # Examples
+    /// ```
+    /// assert_eq!(format!("{}", reword::AsPathCase("user id")), "user/id");
+    /// ```
+    AsPathCase => Path,
+    /// Formats the string as flat case when displayed, without allocating.
+    ///
+    /// # Examples
+    /// ```
+    /// assert_eq!(format!("{}", reword::AsFlatCase("user id")), "userid");
+    /// ```
+    AsFlatCase => Flat,
+    /// Formats the string as upper flat case when displayed, without allocating.
+    ///
+    /// # Examples
+    /// ```
+    /// assert_eq!(format!("{}", reword::AsUpperFlatCase("user id")), "USERID");
+    /// ```
+    AsUpperFlatCase => UpperFlat,
+    /// Formats the string as ada case when displayed, without allocating.
+    ///
+    /// # Examples
+    /// ```
+    /// assert_eq!(format!("{}", reword::AsAdaCase("user id")), "User_Id");
+    /// ```
+    AsAdaCase => Ada,
+}
+
+/// Returns `true` if the input string is already formatted in the provided case.
+///
+/// # Examples
+/// ```
+/// use reword::Case;
+///
+/// assert!(reword::is_case("user_id", Case::Snake));
+/// assert!(!reword::is_case("user-id", Case::Snake));
+/// ```
+pub fn is_case<T: AsRef<str>>(t: T, case: Case) -> bool {
+    let t = t.as_ref();
+    display_eq(t, AsCase(t, case))
+}
+
+/// Returns the case the input string is formatted in.
+///
+/// Returns `None` if the input is not in any case, or if it is in several cases,
+/// like single words such as `abc`. Use [`detect_cases`] to get all of them.
+///
+/// # Examples
+/// ```
+/// use reword::Case;
+///
+/// assert_eq!(reword::detect_case("user-id"), Some(Case::Kebab));
+/// assert_eq!(reword::detect_case("userId"), Some(Case::Camel));
+/// assert_eq!(reword::detect_case("abc"), None);
+/// assert_eq!(reword::detect_case("user id"), None);
+/// ```
+pub fn detect_case<T: AsRef<str>>(t: T) -> Option<Case> {
+    let mut cases = detect_cases(t);
+    let case = cases.next()?;
+    cases.next().is_none().then_some(case)
+}
+
+/// Returns all the cases the input string is formatted in.
+///
+/// # Examples
+/// ```
+/// use reword::Case;
+///
+/// let cases: Vec<Case> = reword::detect_cases("abc").collect();
+/// assert_eq!(cases, [Case::Snake, Case::Kebab, Case::Camel, Case::Dot, Case::Path, Case::Flat]);
+/// ```
+pub fn detect_cases<T: AsRef<str>>(t: T) -> impl Iterator<Item = Case> {
+    Case::ALL
+        .iter()
+        .copied()
+        .filter(move |&case| is_case(&t, case))
+}
+
+/// Formats the input string as a title, using the default [`TitleStyle`].
+///
+/// # Examples
+/// ```
+/// assert_eq!(reword::title_case("the lord of the rings"), "The Lord of the Rings");
+/// assert_eq!(reword::title_case("NASA_mission_log"), "NASA Mission Log");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn title_case<T: AsRef<str>>(t: T) -> String {
+    convert(t, Case::Title)
+}
+
+/// Formats the input string as a title, using the default [`TitleStyle`], and limits the length of the title.
+///
+/// # Examples
+/// ```
+/// assert_eq!(reword::title_case_with_limit("even olsson rogstadkjærnet", 25), "Even O Rogstadkjærnet");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn title_case_with_limit<T: AsRef<str>>(t: T, limit: usize) -> String {
+    convert_with_limit(t, Case::Title, limit)
+}
+
+/// Formats the input string as a title, using the provided style.
+///
+/// # Examples
+/// ```
+/// use reword::TitleStyle;
+///
+/// let t = "the end of an era";
+/// assert_eq!(reword::title_case_with_style(t, TitleStyle::Apa), "The End of an Era");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn title_case_with_style<T: AsRef<str>>(t: T, style: TitleStyle) -> String {
+    CaseBuilder::from(Case::Title)
+        .title_style(style)
+        .build()
+        .convert(t)
+}
+
+/// Formats the input string as a sentence.
+///
+/// # Examples
+/// ```
+/// assert_eq!(reword::sentence_case("user_account_id"), "User account id");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn sentence_case<T: AsRef<str>>(t: T) -> String {
+    convert(t, Case::Sentence)
+}
+
+/// Formats the input string as a sentence and limits the length of the sentence.
+///
+/// # Examples
+/// ```
+/// assert_eq!(reword::sentence_case_with_limit("Even Olsson Rogstadkjærnet", 25), "Even o rogstadkjærnet");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn sentence_case_with_limit<T: AsRef<str>>(t: T, limit: usize) -> String {
+    convert_with_limit(t, Case::Sentence, limit)
 }
 
 /// Formats the input string as a kebab case name.
@@ -11,12 +1045,23 @@ fn pat(c: char) -> bool {
 /// ```
 /// assert_eq!(reword::kebab_case("Even Olsson Rogstadkjærnet"), "even-olsson-rogstadkjærnet");
 /// ```
+#[cfg(feature = "alloc")]
 pub fn kebab_case<T: AsRef<str>>(t: T) -> String {
-    name(t)
-        .split(pat)
-        .filter(|t| !t.is_empty())
-        .map(str::to_lowercase)
-        .fold(String::new(), fold_kebab_case)
+    convert(t, Case::Kebab)
+}
+
+/// Formats the input string as a kebab case name, borrowing it if it is already in kebab case.
+///
+/// # Examples
+/// ```
+/// use std::borrow::Cow;
+///
+/// assert!(matches!(reword::kebab_case_cow("user-id"), Cow::Borrowed("user-id")));
+/// assert_eq!(reword::kebab_case_cow("userId"), "user-id");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn kebab_case_cow(t: &str) -> Cow<'_, str> {
+    convert_cow(t, Case::Kebab)
 }
 
 /// Formats the input string as a kebab case name and limits the length of the name.
@@ -25,12 +1070,9 @@ pub fn kebab_case<T: AsRef<str>>(t: T) -> String {
 /// ```
 /// assert_eq!(reword::kebab_case_with_limit("Even Olsson Rogstadkjærnet", 25), "even-o-rogstadkjærnet");
 /// ```
+#[cfg(feature = "alloc")]
 pub fn kebab_case_with_limit<T: AsRef<str>>(t: T, limit: usize) -> String {
-    name_with_limit(t, limit)
-        .split(pat)
-        .filter(|t| !t.is_empty())
-        .map(str::to_lowercase)
-        .fold(String::new(), fold_kebab_case)
+    convert_with_limit(t, Case::Kebab, limit)
 }
 
 /// Formats the input string as a screaming kebab case name.
@@ -39,12 +1081,9 @@ pub fn kebab_case_with_limit<T: AsRef<str>>(t: T, limit: usize) -> String {
 /// ```
 /// assert_eq!(reword::screaming_kebab_case("Even Olsson Rogstadkjærnet"), "EVEN-OLSSON-ROGSTADKJÆRNET");
 /// ```
+#[cfg(feature = "alloc")]
 pub fn screaming_kebab_case<T: AsRef<str>>(t: T) -> String {
-    name(t)
-        .split(pat)
-        .filter(|t| !t.is_empty())
-        .map(str::to_uppercase)
-        .fold(String::new(), fold_kebab_case)
+    convert(t, Case::ScreamingKebab)
 }
 
 /// Formats the input string as a screaming kebab case name and limits the length of the name.
@@ -53,12 +1092,9 @@ pub fn screaming_kebab_case<T: AsRef<str>>(t: T) -> String {
 /// ```
 /// assert_eq!(reword::screaming_kebab_case_with_limit("Even Olsson Rogstadkjærnet", 25), "EVEN-O-ROGSTADKJÆRNET");
 /// ```
+#[cfg(feature = "alloc")]
 pub fn screaming_kebab_case_with_limit<T: AsRef<str>>(t: T, limit: usize) -> String {
-    name_with_limit(t, limit)
-        .split(pat)
-        .filter(|t| !t.is_empty())
-        .map(str::to_uppercase)
-        .fold(String::new(), fold_kebab_case)
+    convert_with_limit(t, Case::ScreamingKebab, limit)
 }
 
 /// Formats the input string as a snake case name.
@@ -67,12 +1103,23 @@ pub fn screaming_kebab_case_with_limit<T: AsRef<str>>(t: T, limit: usize) -> Str
 /// ```
 /// assert_eq!(reword::snake_case("Even Olsson Rogstadkjærnet"), "even_olsson_rogstadkjærnet");
 /// ```
+#[cfg(feature = "alloc")]
 pub fn snake_case<T: AsRef<str>>(t: T) -> String {
-    name(t)
-        .split(pat)
-        .filter(|t| !t.is_empty())
-        .map(str::to_lowercase)
-        .fold(String::new(), fold_snake_case)
+    convert(t, Case::Snake)
+}
+
+/// Formats the input string as a snake case name, borrowing it if it is already in snake case.
+///
+/// # Examples
+/// ```
+/// use std::borrow::Cow;
+///
+/// assert!(matches!(reword::snake_case_cow("user_id"), Cow::Borrowed("user_id")));
+/// assert_eq!(reword::snake_case_cow("userId"), "user_id");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn snake_case_cow(t: &str) -> Cow<'_, str> {
+    convert_cow(t, Case::Snake)
 }
 
 /// Formats the input string as a snake case name and limits the length of the name.
@@ -81,12 +1128,9 @@ pub fn snake_case<T: AsRef<str>>(t: T) -> String {
 /// ```
 /// assert_eq!(reword::snake_case_with_limit("Even Olsson Rogstadkjærnet", 25), "even_o_rogstadkjærnet");
 /// ```
+#[cfg(feature = "alloc")]
 pub fn snake_case_with_limit<T: AsRef<str>>(t: T, limit: usize) -> String {
-    name_with_limit(t, limit)
-        .split(pat)
-        .filter(|t| !t.is_empty())
-        .map(str::to_lowercase)
-        .fold(String::new(), fold_snake_case)
+    convert_with_limit(t, Case::Snake, limit)
 }
 
 /// Formats the input string as a screaming snake case name.
@@ -95,12 +1139,9 @@ pub fn snake_case_with_limit<T: AsRef<str>>(t: T, limit: usize) -> String {
 /// ```
 /// assert_eq!(reword::screaming_snake_case("Even Olsson Rogstadkjærnet"), "EVEN_OLSSON_ROGSTADKJÆRNET");
 /// ```
+#[cfg(feature = "alloc")]
 pub fn screaming_snake_case<T: AsRef<str>>(t: T) -> String {
-    name(t)
-        .split(pat)
-        .filter(|t| !t.is_empty())
-        .map(str::to_uppercase)
-        .fold(String::new(), fold_snake_case)
+    convert(t, Case::ScreamingSnake)
 }
 
 /// Formats the input string as a screaming snake case name and limits the length of the name.
@@ -109,12 +1150,9 @@ pub fn screaming_snake_case<T: AsRef<str>>(t: T) -> String {
 /// ```
 /// assert_eq!(reword::screaming_snake_case_with_limit("Even Olsson Rogstadkjærnet", 25), "EVEN_O_ROGSTADKJÆRNET");
 /// ```
+#[cfg(feature = "alloc")]
 pub fn screaming_snake_case_with_limit<T: AsRef<str>>(t: T, limit: usize) -> String {
-    name_with_limit(t, limit)
-        .split(pat)
-        .filter(|t| !t.is_empty())
-        .map(str::to_uppercase)
-        .fold(String::new(), fold_snake_case)
+    convert_with_limit(t, Case::ScreamingSnake, limit)
 }
 
 /// Formats the input string as a camel case name.
@@ -123,28 +1161,20 @@ pub fn screaming_snake_case_with_limit<T: AsRef<str>>(t: T, limit: usize) -> Str
 /// ```
 /// assert_eq!(reword::camel_case("Even Olsson Rogstadkjærnet"), "evenOlssonRogstadkjærnet");
 /// ```
+#[cfg(feature = "alloc")]
 pub fn camel_case<T: AsRef<str>>(t: T) -> String {
-    name(t)
-        .split(pat)
-        .filter(|t| !t.is_empty())
-        .enumerate()
-        .map(|(i, word)| to_camel_case(word, i != 0))
-        .fold(String::new(), fold_camel_case)
+    convert(t, Case::Camel)
 }
 
 /// Formats the input string as a camel case name with a limit on the length.
 ///
 /// # Examples
 /// ```
-/// assert_eq!(reword::camel_case_with_limit("Even Olsson Rogstadkjærnet", 25), "evenORogstadkjærnet");
+/// assert_eq!(reword::camel_case_with_limit("Even Olsson Rogstadkjærnet", 20), "evenORogstadkjærnet");
 /// ```
+#[cfg(feature = "alloc")]
 pub fn camel_case_with_limit<T: AsRef<str>>(t: T, limit: usize) -> String {
-    name_with_limit(t, limit)
-        .split(pat)
-        .filter(|t| !t.is_empty())
-        .enumerate()
-        .map(|(i, word)| to_camel_case(word, i != 0))
-        .fold(String::new(), fold_camel_case)
+    convert_with_limit(t, Case::Camel, limit)
 }
 
 /// Formats the input string as a pascal case name.
@@ -153,61 +1183,176 @@ pub fn camel_case_with_limit<T: AsRef<str>>(t: T, limit: usize) -> String {
 /// ```
 /// assert_eq!(reword::pascal_case("Even Olsson Rogstadkjærnet"), "EvenOlssonRogstadkjærnet");
 /// ```
+#[cfg(feature = "alloc")]
 pub fn pascal_case<T: AsRef<str>>(t: T) -> String {
-    name(t)
-        .split(pat)
-        .filter(|t| !t.is_empty())
-        .map(|word| to_camel_case(word, true))
-        .fold(String::new(), fold_camel_case)
+    convert(t, Case::Pascal)
 }
 
 /// Formats the input string as a pascal case name with a limit on the length.
 ///
 /// # Examples
 /// ```
-/// assert_eq!(reword::pascal_case_with_limit("Even Olsson Rogstadkjærnet", 25), "EvenORogstadkjærnet");
+/// assert_eq!(reword::pascal_case_with_limit("Even Olsson Rogstadkjærnet", 20), "EvenORogstadkjærnet");
 /// ```
+#[cfg(feature = "alloc")]
 pub fn pascal_case_with_limit<T: AsRef<str>>(t: T, limit: usize) -> String {
-    name_with_limit(t, limit)
-        .split(pat)
-        .filter(|t| !t.is_empty())
-        .map(|word| to_camel_case(word, true))
-        .fold(String::new(), fold_camel_case)
+    convert_with_limit(t, Case::Pascal, limit)
 }
 
-fn to_camel_case(word: &str, mut upper: bool) -> String {
-    let mut cc = String::new();
-    let mut prev_is_lowercase = false;
+/// Formats the input string as a train case name.
+///
+/// # Examples
+/// ```
+/// assert_eq!(reword::train_case("Even Olsson Rogstadkjærnet"), "Even-Olsson-Rogstadkjærnet");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn train_case<T: AsRef<str>>(t: T) -> String {
+    convert(t, Case::Train)
+}
 
-    for c in word.chars() {
-        if upper {
-            cc.extend(c.to_uppercase());
-        } else {
-            cc.extend(c.to_lowercase());
-        }
+/// Formats the input string as a train case name and limits the length of the name.
+///
+/// # Examples
+/// ```
+/// assert_eq!(reword::train_case_with_limit("Even Olsson Rogstadkjærnet", 25), "Even-O-Rogstadkjærnet");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn train_case_with_limit<T: AsRef<str>>(t: T, limit: usize) -> String {
+    convert_with_limit(t, Case::Train, limit)
+}
 
-        upper = prev_is_lowercase && c.is_uppercase();
-        prev_is_lowercase = c.is_lowercase();
-    }
+/// Formats the input string as a dot case name.
+///
+/// # Examples
+/// ```
+/// assert_eq!(reword::dot_case("Even Olsson Rogstadkjærnet"), "even.olsson.rogstadkjærnet");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn dot_case<T: AsRef<str>>(t: T) -> String {
+    convert(t, Case::Dot)
+}
 
-    cc
+/// Formats the input string as a dot case name and limits the length of the name.
+///
+/// # Examples
+/// ```
+/// assert_eq!(reword::dot_case_with_limit("Even Olsson Rogstadkjærnet", 25), "even.o.rogstadkjærnet");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn dot_case_with_limit<T: AsRef<str>>(t: T, limit: usize) -> String {
+    convert_with_limit(t, Case::Dot, limit)
 }
 
-fn fold_kebab_case(acc: String, w: String) -> String {
-    fold(acc, &w, '-')
+/// Formats the input string as a path case name.
+///
+/// # Examples
+/// ```
+/// assert_eq!(reword::path_case("Even Olsson Rogstadkjærnet"), "even/olsson/rogstadkjærnet");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn path_case<T: AsRef<str>>(t: T) -> String {
+    convert(t, Case::Path)
 }
 
-fn fold_snake_case(acc: String, w: String) -> String {
-    fold(acc, &w, '_')
+/// Formats the input string as a path case name and limits the length of the name.
+///
+/// # Examples
+/// ```
+/// assert_eq!(reword::path_case_with_limit("Even Olsson Rogstadkjærnet", 25), "even/o/rogstadkjærnet");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn path_case_with_limit<T: AsRef<str>>(t: T, limit: usize) -> String {
+    convert_with_limit(t, Case::Path, limit)
 }
 
-fn fold_camel_case(mut acc: String, w: String) -> String {
-    let start_is_num = matches!(w.chars().next(), Some(c) if c.is_numeric());
-    let end_is_num = matches!(acc.chars().last(), Some(c) if c.is_numeric());
-    // Split with _ if two word boundaries are numeric.
-    if start_is_num && end_is_num {
-        acc.push('_');
-    }
-    acc.push_str(&w);
-    acc
+/// Formats the input string as a flat case name.
+///
+/// # Examples
+/// ```
+/// assert_eq!(reword::flat_case("Even Olsson Rogstadkjærnet"), "evenolssonrogstadkjærnet");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn flat_case<T: AsRef<str>>(t: T) -> String {
+    convert(t, Case::Flat)
+}
+
+/// Formats the input string as a flat case name and limits the length of the name.
+///
+/// # Examples
+/// ```
+/// assert_eq!(reword::flat_case_with_limit("Even Olsson Rogstadkjærnet", 20), "evenorogstadkjærnet");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn flat_case_with_limit<T: AsRef<str>>(t: T, limit: usize) -> String {
+    convert_with_limit(t, Case::Flat, limit)
+}
+
+/// Formats the input string as an upper flat case name.
+///
+/// # Examples
+/// ```
+/// assert_eq!(reword::upper_flat_case("Even Olsson Rogstadkjærnet"), "EVENOLSSONROGSTADKJÆRNET");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn upper_flat_case<T: AsRef<str>>(t: T) -> String {
+    convert(t, Case::UpperFlat)
+}
+
+/// Formats the input string as an upper flat case name and limits the length of the name.
+///
+/// # Examples
+/// ```
+/// assert_eq!(reword::upper_flat_case_with_limit("Even Olsson Rogstadkjærnet", 20), "EVENOROGSTADKJÆRNET");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn upper_flat_case_with_limit<T: AsRef<str>>(t: T, limit: usize) -> String {
+    convert_with_limit(t, Case::UpperFlat, limit)
+}
+
+/// Formats the input string as an Ada case name.
+///
+/// # Examples
+/// ```
+/// assert_eq!(reword::ada_case("Even Olsson Rogstadkjærnet"), "Even_Olsson_Rogstadkjærnet");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn ada_case<T: AsRef<str>>(t: T) -> String {
+    convert(t, Case::Ada)
+}
+
+/// Formats the input string as an Ada case name and limits the length of the name.
+///
+/// # Examples
+/// ```
+/// assert_eq!(reword::ada_case_with_limit("Even Olsson Rogstadkjærnet", 25), "Even_O_Rogstadkjærnet");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn ada_case_with_limit<T: AsRef<str>>(t: T, limit: usize) -> String {
+    convert_with_limit(t, Case::Ada, limit)
+}
+
+/// Formats the input string as a cobol case name.
+///
+/// This is the same as [`screaming_kebab_case`].
+///
+/// # Examples
+/// ```
+/// assert_eq!(reword::cobol_case("Even Olsson Rogstadkjærnet"), "EVEN-OLSSON-ROGSTADKJÆRNET");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn cobol_case<T: AsRef<str>>(t: T) -> String {
+    convert(t, Case::ScreamingKebab)
+}
+
+/// Formats the input string as a cobol case name and limits the length of the name.
+///
+/// This is the same as [`screaming_kebab_case_with_limit`].
+///
+/// # Examples
+/// ```
+/// assert_eq!(reword::cobol_case_with_limit("Even Olsson Rogstadkjærnet", 25), "EVEN-O-ROGSTADKJÆRNET");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn cobol_case_with_limit<T: AsRef<str>>(t: T, limit: usize) -> String {
+    convert_with_limit(t, Case::ScreamingKebab, limit)
 }
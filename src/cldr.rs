@@ -0,0 +1,140 @@
+use crate::{Alternation, ListJoiner};
+
+/// The kind of list, as in the CLDR list patterns.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum ListStyle {
+    /// A conjunction, like `a, b and c`.
+    #[default]
+    And,
+    /// A disjunction, like `a, b or c`.
+    Or,
+    /// A list of units, like `3 hours, 5 minutes`.
+    Unit,
+}
+
+/// Creates a joiner from the infixes of the `middle`, `end` and `2` patterns, and
+/// the overflow phrase of the language.
+///
+/// The `start` pattern is the same as `middle` in all the bundled languages.
+const fn list(
+    middle: &'static str,
+    end: &'static str,
+    pair: &'static str,
+    more: &'static str,
+) -> ListJoiner<'static> {
+    ListJoiner::new()
+        .separator(middle)
+        .conjunction(end)
+        .pair_separator(pair)
+        .overflow(more)
+}
+
+/// The list patterns of each language, in the order of [`ListStyle`].
+const PATTERNS: &[(&str, [ListJoiner<'static>; 3])] = &[
+    (
+        "en",
+        [
+            list(", ", ", and ", " and ", "{} more"),
+            list(", ", ", or ", " or ", "{} more"),
+            list(", ", ", ", ", ", "{} more"),
+        ],
+    ),
+    (
+        "nb",
+        [
+            list(", ", " og ", " og ", "{} til"),
+            list(", ", " eller ", " eller ", "{} til"),
+            list(", ", " og ", ", ", "{} til"),
+        ],
+    ),
+    (
+        "de",
+        [
+            list(", ", " und ", " und ", "{} weitere"),
+            list(", ", " oder ", " oder ", "{} weitere"),
+            list(", ", " und ", ", ", "{} weitere"),
+        ],
+    ),
+    (
+        "fr",
+        [
+            list(", ", " et ", " et ", "{} autres"),
+            list(", ", " ou ", " ou ", "{} autres"),
+            list(", ", " et ", " et ", "{} autres"),
+        ],
+    ),
+    (
+        "es",
+        [
+            list(", ", " y ", " y ", "{} más").alternation(Alternation::Spanish),
+            list(", ", " o ", " o ", "{} más").alternation(Alternation::Spanish),
+            list(", ", " y ", " y ", "{} más").alternation(Alternation::Spanish),
+        ],
+    ),
+    (
+        "it",
+        [
+            list(", ", " e ", " e ", "{} altri").alternation(Alternation::Italian),
+            list(", ", " o ", " o ", "{} altri"),
+            list(", ", " e ", " e ", "{} altri").alternation(Alternation::Italian),
+        ],
+    ),
+    (
+        "ja",
+        [
+            list("、", "、", "、", "他{}件"),
+            list("、", "、または", "または", "他{}件"),
+            list(" ", " ", " ", "他{}件"),
+        ],
+    ),
+    (
+        "zh",
+        [
+            list("、", "和", "和", "其他{}项"),
+            list("、", "或", "或", "其他{}项"),
+            list("", "", "", "其他{}项"),
+        ],
+    ),
+];
+
+impl ListJoiner<'static> {
+    /// Returns a joiner that follows the CLDR list patterns of a language tag,
+    /// like `nb` or `de-AT`.
+    ///
+    /// Returns `None` if the language is not bundled. The bundled languages are
+    /// English, Norwegian, German, French, Spanish, Italian, Japanese and Chinese.
+    /// The Spanish and Italian joiners change the conjunction as described by
+    /// [`Alternation`], which the patterns can not express. Each joiner has the
+    /// overflow phrase of its language, used by [`ListJoiner::join_limited`].
+    ///
+    /// # Examples
+    /// ```
+    /// use reword::{ListJoiner, ListStyle};
+    ///
+    /// # #[cfg(feature = "alloc")] {
+    /// let nb = ListJoiner::localized("nb-NO", ListStyle::And).unwrap();
+    /// assert_eq!(nb.join(["a", "b", "c"]), "a, b og c");
+    ///
+    /// let ja = ListJoiner::localized("ja", ListStyle::And).unwrap();
+    /// assert_eq!(ja.join(["a", "b", "c"]), "a、b、c");
+    ///
+    /// let en = ListJoiner::localized("en-US", ListStyle::Or).unwrap();
+    /// assert_eq!(en.join(["a", "b", "c"]), "a, b, or c");
+    ///
+    /// let es = ListJoiner::localized("es", ListStyle::And).unwrap();
+    /// assert_eq!(es.join_limited(["a", "b", "c", "d"], 2), "a, b y 2 más");
+    /// # }
+    /// ```
+    pub fn localized(tag: &str, style: ListStyle) -> Option<ListJoiner<'static>> {
+        let language = tag.split(['-', '_']).next().unwrap_or_default();
+        let language = match language {
+            l if l.eq_ignore_ascii_case("no") || l.eq_ignore_ascii_case("nn") => "nb",
+            l => l,
+        };
+        let (_, lists) = PATTERNS
+            .iter()
+            .find(|(l, _)| l.eq_ignore_ascii_case(language))?;
+        Some(lists[style as usize])
+    }
+}
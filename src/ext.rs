@@ -0,0 +1,228 @@
+use crate::{Case, Locale, TitleStyle, Unit};
+use alloc::string::String;
+
+/// Makes the conversions callable as methods on strings.
+///
+/// # Examples
+/// ```
+/// use reword::RewordExt;
+///
+/// assert_eq!("userId".to_snake_case(), "user_id");
+/// assert_eq!(String::from("(Even),Olsson&Rogstadkjærnet?").to_name(), "Even Olsson Rogstadkjærnet");
+/// assert_eq!("Even Olsson Rogstadkjærnet".to_username_with_limit(12), "evenor");
+/// ```
+pub trait RewordExt: AsRef<str> {
+    /// See [`convert`](crate::convert).
+    fn to_case(&self, case: Case) -> String {
+        crate::convert(self, case)
+    }
+
+    /// See [`convert_with_limit`](crate::convert_with_limit).
+    fn to_case_with_limit(&self, case: Case, limit: usize) -> String {
+        crate::convert_with_limit(self, case, limit)
+    }
+
+    /// See [`convert_with_limit_unit`](crate::convert_with_limit_unit).
+    fn to_case_with_limit_unit(&self, case: Case, limit: usize, unit: Unit) -> String {
+        crate::convert_with_limit_unit(self, case, limit, unit)
+    }
+
+    /// See [`convert_with_locale`](crate::convert_with_locale).
+    fn to_case_with_locale(&self, case: Case, locale: Locale) -> String {
+        crate::convert_with_locale(self, case, locale)
+    }
+
+    /// See [`is_case`](crate::is_case).
+    fn is_case(&self, case: Case) -> bool {
+        crate::is_case(self, case)
+    }
+
+    /// See [`detect_case`](crate::detect_case).
+    fn detect_case(&self) -> Option<Case> {
+        crate::detect_case(self)
+    }
+
+    /// See [`snake_case`](crate::snake_case).
+    fn to_snake_case(&self) -> String {
+        crate::snake_case(self)
+    }
+
+    /// See [`snake_case_with_limit`](crate::snake_case_with_limit).
+    fn to_snake_case_with_limit(&self, limit: usize) -> String {
+        crate::snake_case_with_limit(self, limit)
+    }
+
+    /// See [`screaming_snake_case`](crate::screaming_snake_case).
+    fn to_screaming_snake_case(&self) -> String {
+        crate::screaming_snake_case(self)
+    }
+
+    /// See [`screaming_snake_case_with_limit`](crate::screaming_snake_case_with_limit).
+    fn to_screaming_snake_case_with_limit(&self, limit: usize) -> String {
+        crate::screaming_snake_case_with_limit(self, limit)
+    }
+
+    /// See [`kebab_case`](crate::kebab_case).
+    fn to_kebab_case(&self) -> String {
+        crate::kebab_case(self)
+    }
+
+    /// See [`kebab_case_with_limit`](crate::kebab_case_with_limit).
+    fn to_kebab_case_with_limit(&self, limit: usize) -> String {
+        crate::kebab_case_with_limit(self, limit)
+    }
+
+    /// See [`screaming_kebab_case`](crate::screaming_kebab_case).
+    fn to_screaming_kebab_case(&self) -> String {
+        crate::screaming_kebab_case(self)
+    }
+
+    /// See [`screaming_kebab_case_with_limit`](crate::screaming_kebab_case_with_limit).
+    fn to_screaming_kebab_case_with_limit(&self, limit: usize) -> String {
+        crate::screaming_kebab_case_with_limit(self, limit)
+    }
+
+    /// See [`camel_case`](crate::camel_case).
+    fn to_camel_case(&self) -> String {
+        crate::camel_case(self)
+    }
+
+    /// See [`camel_case_with_limit`](crate::camel_case_with_limit).
+    fn to_camel_case_with_limit(&self, limit: usize) -> String {
+        crate::camel_case_with_limit(self, limit)
+    }
+
+    /// See [`pascal_case`](crate::pascal_case).
+    fn to_pascal_case(&self) -> String {
+        crate::pascal_case(self)
+    }
+
+    /// See [`pascal_case_with_limit`](crate::pascal_case_with_limit).
+    fn to_pascal_case_with_limit(&self, limit: usize) -> String {
+        crate::pascal_case_with_limit(self, limit)
+    }
+
+    /// See [`title_case`](crate::title_case).
+    fn to_title_case(&self) -> String {
+        crate::title_case(self)
+    }
+
+    /// See [`title_case_with_limit`](crate::title_case_with_limit).
+    fn to_title_case_with_limit(&self, limit: usize) -> String {
+        crate::title_case_with_limit(self, limit)
+    }
+
+    /// See [`title_case_with_style`](crate::title_case_with_style).
+    fn to_title_case_with_style(&self, style: TitleStyle) -> String {
+        crate::title_case_with_style(self, style)
+    }
+
+    /// See [`sentence_case`](crate::sentence_case).
+    fn to_sentence_case(&self) -> String {
+        crate::sentence_case(self)
+    }
+
+    /// See [`sentence_case_with_limit`](crate::sentence_case_with_limit).
+    fn to_sentence_case_with_limit(&self, limit: usize) -> String {
+        crate::sentence_case_with_limit(self, limit)
+    }
+
+    /// See [`train_case`](crate::train_case).
+    fn to_train_case(&self) -> String {
+        crate::train_case(self)
+    }
+
+    /// See [`train_case_with_limit`](crate::train_case_with_limit).
+    fn to_train_case_with_limit(&self, limit: usize) -> String {
+        crate::train_case_with_limit(self, limit)
+    }
+
+    /// See [`dot_case`](crate::dot_case).
+    fn to_dot_case(&self) -> String {
+        crate::dot_case(self)
+    }
+
+    /// See [`dot_case_with_limit`](crate::dot_case_with_limit).
+    fn to_dot_case_with_limit(&self, limit: usize) -> String {
+        crate::dot_case_with_limit(self, limit)
+    }
+
+    /// See [`path_case`](crate::path_case).
+    fn to_path_case(&self) -> String {
+        crate::path_case(self)
+    }
+
+    /// See [`path_case_with_limit`](crate::path_case_with_limit).
+    fn to_path_case_with_limit(&self, limit: usize) -> String {
+        crate::path_case_with_limit(self, limit)
+    }
+
+    /// See [`flat_case`](crate::flat_case).
+    fn to_flat_case(&self) -> String {
+        crate::flat_case(self)
+    }
+
+    /// See [`flat_case_with_limit`](crate::flat_case_with_limit).
+    fn to_flat_case_with_limit(&self, limit: usize) -> String {
+        crate::flat_case_with_limit(self, limit)
+    }
+
+    /// See [`upper_flat_case`](crate::upper_flat_case).
+    fn to_upper_flat_case(&self) -> String {
+        crate::upper_flat_case(self)
+    }
+
+    /// See [`upper_flat_case_with_limit`](crate::upper_flat_case_with_limit).
+    fn to_upper_flat_case_with_limit(&self, limit: usize) -> String {
+        crate::upper_flat_case_with_limit(self, limit)
+    }
+
+    /// See [`ada_case`](crate::ada_case).
+    fn to_ada_case(&self) -> String {
+        crate::ada_case(self)
+    }
+
+    /// See [`ada_case_with_limit`](crate::ada_case_with_limit).
+    fn to_ada_case_with_limit(&self, limit: usize) -> String {
+        crate::ada_case_with_limit(self, limit)
+    }
+
+    /// See [`cobol_case`](crate::cobol_case).
+    fn to_cobol_case(&self) -> String {
+        crate::cobol_case(self)
+    }
+
+    /// See [`cobol_case_with_limit`](crate::cobol_case_with_limit).
+    fn to_cobol_case_with_limit(&self, limit: usize) -> String {
+        crate::cobol_case_with_limit(self, limit)
+    }
+
+    /// See [`name`](crate::name).
+    fn to_name(&self) -> String {
+        crate::name(self)
+    }
+
+    /// See [`name_with_limit`](crate::name_with_limit).
+    fn to_name_with_limit(&self, limit: usize) -> String {
+        crate::name_with_limit(self, limit)
+    }
+
+    /// See [`username`](crate::username).
+    fn to_username(&self) -> String {
+        crate::username(self)
+    }
+
+    /// See [`username_with_limit`](crate::username_with_limit).
+    fn to_username_with_limit(&self, limit: usize) -> String {
+        crate::username_with_limit(self, limit)
+    }
+
+    /// See [`username_with_locale`](crate::username_with_locale).
+    fn to_username_with_locale(&self, locale: Locale) -> String {
+        crate::username_with_locale(self, locale)
+    }
+}
+
+impl RewordExt for str {}
+
+impl RewordExt for String {}
@@ -0,0 +1,188 @@
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+/// A programming language that identifiers can be created for.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum Language {
+    /// Keywords are escaped as raw identifiers, like `r#type`.
+    Rust,
+    /// Keywords are escaped with a trailing underscore, like `class_`.
+    Python,
+    /// Keywords are escaped with a trailing underscore, like `delete_`.
+    JavaScript,
+    /// Keywords are escaped with a trailing underscore, like `delete_`.
+    TypeScript,
+    /// Keywords are escaped with a trailing underscore, like `type_`.
+    Go,
+    /// Keywords are escaped with a trailing underscore, like `class_`.
+    Java,
+    /// Keywords are escaped with a trailing underscore, like `int_`.
+    ///
+    /// Only ASCII letters are allowed in identifiers.
+    C,
+    /// Keywords are escaped with a trailing underscore, like `select_`.
+    ///
+    /// Keywords are matched without regard to case.
+    Sql,
+}
+
+impl Language {
+    /// Returns the reserved words of the language, separated by whitespace.
+    const fn keywords(self) -> &'static str {
+        match self {
+            Language::Rust => concat!(
+                "_ abstract as async await become box break const continue crate do dyn else enum ",
+                "extern false final fn for gen if impl in let loop macro match mod move mut ",
+                "override priv pub ref return self Self static struct super trait true try type ",
+                "typeof unsafe unsized use virtual where while yield",
+            ),
+            Language::Python => concat!(
+                "False None True and as assert async await break class continue def del elif else ",
+                "except finally for from global if import in is lambda nonlocal not or pass raise ",
+                "return try while with yield",
+            ),
+            Language::JavaScript | Language::TypeScript => concat!(
+                "arguments await break case catch class const continue debugger default delete do ",
+                "else enum eval export extends false finally for function if implements import in ",
+                "instanceof interface let new null package private protected public return static ",
+                "super switch this throw true try typeof var void while with yield",
+            ),
+            Language::Go => concat!(
+                "break case chan const continue default defer else fallthrough for func go goto ",
+                "if import interface map package range return select struct switch type var",
+            ),
+            Language::Java => concat!(
+                "_ abstract assert boolean break byte case catch char class const continue ",
+                "default do double else enum extends false final finally float for goto if ",
+                "implements import instanceof int interface long native new null package private ",
+                "protected public return short static strictfp super switch synchronized this ",
+                "throw throws transient true try void volatile while",
+            ),
+            Language::C => concat!(
+                "alignas alignof auto bool break case char const constexpr continue default do ",
+                "double else enum extern false float for goto if inline int long nullptr register ",
+                "restrict return short signed sizeof static static_assert struct switch ",
+                "thread_local true typedef typeof typeof_unqual union unsigned void volatile ",
+                "while _Alignas _Alignof _Atomic _BitInt _Bool _Complex _Decimal128 _Decimal32 ",
+                "_Decimal64 _Generic _Imaginary _Noreturn _Static_assert _Thread_local",
+            ),
+            Language::Sql => concat!(
+                "add all alter and any as asc between by case check column constraint create ",
+                "cross current_date current_time current_timestamp current_user default delete ",
+                "desc distinct drop else end except exists false fetch for foreign from full ",
+                "grant group having in index inner insert intersect into is join key left like ",
+                "limit not null offset on or order outer primary references revoke right select ",
+                "session_user set some table then to true union unique update user using values ",
+                "view when where with",
+            ),
+        }
+    }
+
+    /// Returns `true` if the word is a reserved word in the language.
+    ///
+    /// # Examples
+    /// ```
+    /// use reword::Language;
+    ///
+    /// assert!(Language::Rust.is_keyword("match"));
+    /// assert!(Language::Sql.is_keyword("SELECT"));
+    /// assert!(!Language::Python.is_keyword("match"));
+    /// ```
+    pub fn is_keyword(self, word: &str) -> bool {
+        let mut keywords = self.keywords().split_ascii_whitespace();
+        match self {
+            Language::Sql => keywords.any(|k| k.eq_ignore_ascii_case(word)),
+            _ => keywords.any(|k| k == word),
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    fn is_start(self, c: char) -> bool {
+        match self {
+            Language::Rust | Language::Python => c == '_' || unicode_ident::is_xid_start(c),
+            Language::JavaScript | Language::TypeScript => {
+                c == '_' || c == '$' || unicode_ident::is_xid_start(c)
+            }
+            Language::Java => c == '_' || c == '$' || c.is_alphabetic(),
+            Language::Go => c == '_' || is_letter(c),
+            Language::C => c == '_' || c.is_ascii_alphabetic(),
+            Language::Sql => c == '_' || c.is_alphabetic(),
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    fn is_continue(self, c: char) -> bool {
+        match self {
+            Language::Rust | Language::Python => unicode_ident::is_xid_continue(c),
+            Language::JavaScript | Language::TypeScript => {
+                c == '$' || unicode_ident::is_xid_continue(c)
+            }
+            Language::Go => c == '_' || is_letter(c) || is_digit(c),
+            Language::C => c == '_' || c.is_ascii_alphanumeric(),
+            Language::Java | Language::Sql => self.is_start(c) || c.is_alphanumeric(),
+        }
+    }
+}
+
+/// Returns `true` if the character is a Unicode letter, in the `L` categories.
+///
+/// Letter numbers like `Ⅻ` are alphabetic and start identifiers, but are not letters.
+#[cfg(feature = "alloc")]
+fn is_letter(c: char) -> bool {
+    c.is_alphabetic() && !c.is_numeric() && unicode_ident::is_xid_start(c)
+}
+
+/// Returns `true` if the character is a decimal digit, in the `Nd` category.
+#[cfg(feature = "alloc")]
+fn is_digit(c: char) -> bool {
+    // The Ethiopic digits and the Tai Lue one continue identifiers, but are not decimal digits.
+    let other = matches!(c, '\u{1369}'..='\u{1371}' | '\u{19DA}');
+    c.is_numeric() && !c.is_alphabetic() && !other && unicode_ident::is_xid_continue(c)
+}
+
+/// Creates an identifier that is valid in the language from the provided string.
+///
+/// Characters that are not allowed in an identifier are replaced with `_`, an `_`
+/// is added in front of identifiers that starts with a digit, and keywords are
+/// escaped. This is usually used on the output of a case converter.
+///
+/// # Examples
+/// ```
+/// use reword::Language;
+///
+/// assert_eq!(reword::identifier("type", Language::Rust), "r#type");
+/// assert_eq!(reword::identifier("class", Language::Python), "class_");
+/// assert_eq!(reword::identifier("1st_place", Language::Go), "_1st_place");
+/// assert_eq!(reword::identifier(reword::snake_case("match"), Language::Rust), "r#match");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn identifier<T: AsRef<str>>(t: T, language: Language) -> String {
+    let t = t.as_ref();
+    let mut ident = String::with_capacity(t.len() + 2);
+    for (i, c) in t.chars().enumerate() {
+        if i == 0 && !language.is_start(c) && language.is_continue(c) {
+            ident.push('_');
+        }
+        if language.is_continue(c) {
+            ident.push(c);
+        } else {
+            ident.push('_');
+        }
+    }
+
+    if ident.is_empty() {
+        ident.push('_');
+    }
+
+    if language.is_keyword(&ident) {
+        match (language, ident.as_str()) {
+            // These can not be raw identifiers.
+            (Language::Rust, "_" | "crate" | "self" | "Self" | "super") => ident.push('_'),
+            (Language::Rust, _) => ident.insert_str(0, "r#"),
+            _ => ident.push('_'),
+        }
+    }
+
+    ident
+}
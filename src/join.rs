@@ -1,4 +1,7 @@
+use crate::{CapacityError, write_into};
+#[cfg(feature = "alloc")]
 use alloc::string::String;
+use core::fmt;
 
 /// Join the list with an 'or' before the last element of the list.
 ///
@@ -7,12 +10,64 @@ use alloc::string::String;
 /// assert_eq!(reword::or_join(&["a", "b"]), "a or b");
 /// assert_eq!(reword::or_join(&["a", "b", "c"]), "a, b or c");
 /// ```
+#[cfg(feature = "alloc")]
 pub fn or_join<I>(iter: I) -> String
 where
     I: IntoIterator,
     I::Item: AsRef<str>,
 {
-    join(iter, ", ", " or ")
+    ListJoiner::OR.join(iter)
+}
+
+/// Join the list with an 'or' before the last element of the list, and a serial
+/// comma before the 'or' when there are three or more elements.
+///
+/// This is also known as the Oxford comma.
+///
+/// # Examples
+/// ```
+/// assert_eq!(reword::or_join_serial(&["a", "b"]), "a or b");
+/// assert_eq!(reword::or_join_serial(&["a", "b", "c"]), "a, b, or c");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn or_join_serial<I>(iter: I) -> String
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    ListJoiner::OR.serial(true).join(iter)
+}
+
+/// Join at most `max` elements of the list with an 'or' before the last element,
+/// followed by the number of elements that are left out.
+///
+/// # Examples
+/// ```
+/// assert_eq!(reword::or_join_limited(&["a", "b", "c"], 3), "a, b or c");
+/// assert_eq!(reword::or_join_limited(&["a", "b", "c", "d", "e"], 3), "a, b, c or 2 more");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn or_join_limited<I>(iter: I, max: usize) -> String
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    ListJoiner::OR.join_limited(iter, max)
+}
+
+/// Join the list with an 'or' before the last element of the list into the buffer.
+///
+/// # Examples
+/// ```
+/// let mut buf = [0; 16];
+/// assert_eq!(reword::or_join_into(&["a", "b", "c"], &mut buf), Ok("a, b or c"));
+/// ```
+pub fn or_join_into<I>(iter: I, buf: &mut [u8]) -> Result<&str, CapacityError>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    ListJoiner::OR.join_into(iter, buf)
 }
 
 /// Join the list with an 'and' before the last element of the list.
@@ -22,38 +77,400 @@ where
 /// assert_eq!(reword::and_join(&["a", "b"]), "a and b");
 /// assert_eq!(reword::and_join(&["a", "b", "c"]), "a, b and c");
 /// ```
+#[cfg(feature = "alloc")]
 pub fn and_join<I>(iter: I) -> String
 where
     I: IntoIterator,
     I::Item: AsRef<str>,
 {
-    join(iter, ", ", " and ")
+    ListJoiner::AND.join(iter)
 }
 
-fn join<I>(iter: I, mid_sep: &str, end_sep: &str) -> String
+/// Join the list with an 'and' before the last element of the list, and a serial
+/// comma before the 'and' when there are three or more elements.
+///
+/// This is also known as the Oxford comma.
+///
+/// # Examples
+/// ```
+/// assert_eq!(reword::and_join_serial(&["a", "b"]), "a and b");
+/// assert_eq!(reword::and_join_serial(&["a", "b", "c"]), "a, b, and c");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn and_join_serial<I>(iter: I) -> String
 where
     I: IntoIterator,
     I::Item: AsRef<str>,
 {
-    let mut iter = iter.into_iter();
-    let Some(first) = iter.next() else {
-        return String::new();
-    };
+    ListJoiner::AND.serial(true).join(iter)
+}
 
-    let (lower, upper) = iter.size_hint();
-    let mut string = String::with_capacity(upper.unwrap_or(lower) * 8);
-    string.push_str(first.as_ref());
-    let Some(mut next) = iter.next() else {
-        return string;
-    };
+/// Join at most `max` elements of the list with an 'and' before the last element,
+/// followed by the number of elements that are left out.
+///
+/// # Examples
+/// ```
+/// assert_eq!(reword::and_join_limited(&["a", "b", "c"], 3), "a, b and c");
+/// assert_eq!(reword::and_join_limited(&["a", "b", "c", "d", "e", "f", "g"], 3), "a, b, c and 4 more");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn and_join_limited<I>(iter: I, max: usize) -> String
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    ListJoiner::AND.join_limited(iter, max)
+}
+
+/// Join the list with an 'and' before the last element of the list into the buffer.
+///
+/// # Examples
+/// ```
+/// let mut buf = [0; 16];
+/// assert_eq!(reword::and_join_into(&["a", "b", "c"], &mut buf), Ok("a, b and c"));
+/// assert!(reword::and_join_into(&["a", "b", "c"], &mut buf[..4]).is_err());
+/// ```
+pub fn and_join_into<I>(iter: I, buf: &mut [u8]) -> Result<&str, CapacityError>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    ListJoiner::AND.join_into(iter, buf)
+}
 
-    for peek in iter {
-        string.push_str(mid_sep);
-        string.push_str(next.as_ref());
-        next = peek;
+/// Joins a list with configurable separators.
+///
+/// The elements are separated by the separator, except the last one which is
+/// preceded by the conjunction. A list of only two elements is separated by the
+/// pair separator instead, which defaults to the conjunction.
+///
+/// # Examples
+/// ```
+/// use reword::ListJoiner;
+///
+/// # #[cfg(feature = "alloc")] {
+/// let nor = ListJoiner::new().separator("; ").conjunction(" nor ").serial(true);
+/// assert_eq!(nor.join(["a", "b", "c"]), "a; b; nor c");
+///
+/// let slash = ListJoiner::new().separator(" / ").conjunction(" / ");
+/// assert_eq!(slash.join(["a", "b", "c"]), "a / b / c");
+///
+/// let amp = ListJoiner::AND.conjunction(" & ").serial(true);
+/// assert_eq!(amp.join(["a", "b", "c"]), "a, b, & c");
+/// assert_eq!(amp.join(["a", "b"]), "a & b");
+/// # }
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ListJoiner<'a> {
+    separator: &'a str,
+    conjunction: &'a str,
+    pair_separator: Option<&'a str>,
+    serial: bool,
+    alternation: Alternation,
+    overflow: &'a str,
+}
+
+impl<'a> ListJoiner<'a> {
+    /// Joins with an 'and' before the last element, like `a, b and c`.
+    pub const AND: ListJoiner<'static> = ListJoiner::new().conjunction(" and ");
+    /// Joins with an 'or' before the last element, like `a, b or c`.
+    pub const OR: ListJoiner<'static> = ListJoiner::new().conjunction(" or ");
+
+    /// Returns a joiner that separates all the elements with `, `.
+    pub const fn new() -> ListJoiner<'a> {
+        ListJoiner {
+            separator: ", ",
+            conjunction: ", ",
+            pair_separator: None,
+            serial: false,
+            alternation: Alternation::None,
+            overflow: "{} more",
+        }
+    }
+
+    /// Sets the separator between the elements, except before the last one.
+    pub const fn separator(mut self, separator: &'a str) -> ListJoiner<'a> {
+        self.separator = separator;
+        self
+    }
+
+    /// Sets the conjunction before the last element, including the spaces around it.
+    pub const fn conjunction(mut self, conjunction: &'a str) -> ListJoiner<'a> {
+        self.conjunction = conjunction;
+        self
+    }
+
+    /// Sets the separator used when there are only two elements.
+    ///
+    /// Defaults to the conjunction.
+    pub const fn pair_separator(mut self, separator: &'a str) -> ListJoiner<'a> {
+        self.pair_separator = Some(separator);
+        self
+    }
+
+    /// Sets whether the separator, without trailing whitespace, is written before
+    /// the conjunction when there are three or more elements, like `a, b, and c`.
+    ///
+    /// This is also known as the Oxford comma.
+    pub const fn serial(mut self, serial: bool) -> ListJoiner<'a> {
+        self.serial = serial;
+        self
+    }
+
+    /// Sets the rules used to change the conjunction based on the sound of the last element.
+    ///
+    /// Defaults to [`Alternation::None`].
+    pub const fn alternation(mut self, alternation: Alternation) -> ListJoiner<'a> {
+        self.alternation = alternation;
+        self
+    }
+
+    /// Sets the phrase used in place of the elements that are left out of a
+    /// limited list, where `{}` is replaced by their number.
+    ///
+    /// Defaults to `{} more`.
+    pub const fn overflow(mut self, phrase: &'a str) -> ListJoiner<'a> {
+        self.overflow = phrase;
+        self
+    }
+
+    /// Joins the elements of the list.
+    #[cfg(feature = "alloc")]
+    pub fn join<I>(&self, iter: I) -> String
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let iter = iter.into_iter();
+        let (lower, upper) = iter.size_hint();
+        let mut string = String::with_capacity(upper.unwrap_or(lower) * 8);
+        self.write(&mut string, iter)
+            .expect("writing to a string can not fail");
+        string
+    }
+
+    /// Joins the elements of the list into the buffer.
+    pub fn join_into<'b, I>(&self, iter: I, buf: &'b mut [u8]) -> Result<&'b str, CapacityError>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        write_into(buf, |mut w| self.write(&mut w, iter))
+    }
+
+    /// Writes the joined elements of the list to the writer, without allocating.
+    pub fn write<W, I>(&self, w: &mut W, iter: I) -> fmt::Result
+    where
+        W: fmt::Write,
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        self.write_elements(w, iter.into_iter().map(Element::Item))
+    }
+
+    /// Joins at most `max` elements of the list, followed by the overflow phrase
+    /// if any elements are left out.
+    #[cfg(feature = "alloc")]
+    pub fn join_limited<I>(&self, iter: I, max: usize) -> String
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let iter = iter.into_iter();
+        let (lower, upper) = iter.size_hint();
+        let mut string = String::with_capacity((upper.unwrap_or(lower).min(max) + 1) * 8);
+        self.write_limited(&mut string, iter, max)
+            .expect("writing to a string can not fail");
+        string
+    }
+
+    /// Joins at most `max` elements of the list into the buffer, followed by the
+    /// overflow phrase if any elements are left out.
+    pub fn join_limited_into<'b, I>(
+        &self,
+        iter: I,
+        max: usize,
+        buf: &'b mut [u8],
+    ) -> Result<&'b str, CapacityError>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        write_into(buf, |mut w| self.write_limited(&mut w, iter, max))
+    }
+
+    /// Writes at most `max` elements of the list to the writer, followed by the
+    /// overflow phrase if any elements are left out.
+    ///
+    /// The elements that are left out are only counted.
+    ///
+    /// # Examples
+    /// ```
+    /// use reword::ListJoiner;
+    ///
+    /// let mut s = String::new();
+    /// ListJoiner::OR.overflow("{} others").write_limited(&mut s, ["a", "b", "c", "d"], 2).unwrap();
+    /// assert_eq!(s, "a, b or 2 others");
+    /// ```
+    pub fn write_limited<W, I>(&self, w: &mut W, iter: I, max: usize) -> fmt::Result
+    where
+        W: fmt::Write,
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let mut iter = iter.into_iter();
+        let mut left = Some(max);
+        let elements = core::iter::from_fn(|| match &mut left {
+            Some(0) => {
+                left = None;
+                let more = iter.by_ref().count();
+                (more != 0).then_some(Element::More(more))
+            }
+            Some(n) => {
+                *n -= 1;
+                iter.next().map(Element::Item)
+            }
+            None => None,
+        });
+        self.write_elements(w, elements)
+    }
+
+    fn write_elements<W, I, T>(&self, w: &mut W, mut iter: I) -> fmt::Result
+    where
+        W: fmt::Write,
+        I: Iterator<Item = Element<T>>,
+        T: AsRef<str>,
+    {
+        let Some(first) = iter.next() else {
+            return Ok(());
+        };
+
+        self.write_element(w, &first)?;
+        let Some(mut next) = iter.next() else {
+            return Ok(());
+        };
+
+        let mut pair = true;
+        for peek in iter {
+            w.write_str(self.separator)?;
+            self.write_element(w, &next)?;
+            next = peek;
+            pair = false;
+        }
+
+        let start = match &next {
+            Element::Item(item) => item.as_ref(),
+            Element::More(_) => self.overflow,
+        };
+        match self.pair_separator {
+            Some(sep) if pair => self.alternation.write(w, sep, start)?,
+            _ if pair || !self.serial => self.alternation.write(w, self.conjunction, start)?,
+            _ => {
+                w.write_str(self.separator.trim_end())?;
+                self.alternation.write(w, self.conjunction, start)?;
+            }
+        }
+        self.write_element(w, &next)
     }
 
-    string.push_str(end_sep);
-    string.push_str(next.as_ref());
-    string
+    fn write_element<W, T>(&self, w: &mut W, element: &Element<T>) -> fmt::Result
+    where
+        W: fmt::Write,
+        T: AsRef<str>,
+    {
+        match element {
+            Element::Item(item) => w.write_str(item.as_ref()),
+            Element::More(more) => match self.overflow.split_once("{}") {
+                Some((head, tail)) => write!(w, "{head}{more}{tail}"),
+                None => w.write_str(self.overflow),
+            },
+        }
+    }
+}
+
+/// An element of a list written by a [`ListJoiner`].
+enum Element<T> {
+    Item(T),
+    /// The number of elements that are left out.
+    More(usize),
+}
+
+impl Default for ListJoiner<'_> {
+    fn default() -> Self {
+        ListJoiner::new()
+    }
+}
+
+/// The rules used to change a conjunction based on the sound of the word after it.
+///
+/// The rules only apply when the conjunction, ignoring the surrounding whitespace,
+/// ends with the word that is changed.
+///
+/// # Examples
+/// ```
+/// use reword::{Alternation, ListJoiner};
+///
+/// # #[cfg(feature = "alloc")] {
+/// let y = ListJoiner::new().conjunction(" y ").alternation(Alternation::Spanish);
+/// assert_eq!(y.join(["padres", "hijos"]), "padres e hijos");
+/// assert_eq!(y.join(["agua", "hielo"]), "agua y hielo");
+///
+/// let o = ListJoiner::new().conjunction(" o ").alternation(Alternation::Spanish);
+/// assert_eq!(o.join(["siete", "ocho"]), "siete u ocho");
+///
+/// let e = ListJoiner::new().conjunction(" e ").alternation(Alternation::Italian);
+/// assert_eq!(e.join(["io", "ella"]), "io ed ella");
+/// # }
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum Alternation {
+    /// The conjunction is never changed.
+    #[default]
+    None,
+    /// `y` becomes `e` before an /i/ sound, like `padres e hijos`, except before
+    /// a diphthong, like `agua y hielo`. `o` becomes `u` before an /o/ sound, like
+    /// `siete u ocho`.
+    Spanish,
+    /// `e` becomes `ed` before an /e/ sound, like `io ed ella`.
+    Italian,
+}
+
+impl Alternation {
+    /// Returns the word that is changed and what it is changed to before the word.
+    fn change(self, next: &str) -> Option<(&'static str, &'static str)> {
+        let mut sound = next.trim_start().chars().flat_map(char::to_lowercase);
+        match self {
+            Alternation::Spanish => {
+                // The h is silent.
+                let mut sound = sound.skip_while(|&c| c == 'h');
+                match (sound.next(), sound.next()) {
+                    (Some('i'), Some('a' | 'e' | 'o' | 'u' | 'á' | 'é' | 'ó' | 'ú')) => None,
+                    (Some('i' | 'í'), _) => Some(("y", "e")),
+                    (Some('o' | 'ó'), _) => Some(("o", "u")),
+                    _ => None,
+                }
+            }
+            Alternation::Italian => match sound.next() {
+                Some('e' | 'é' | 'è') => Some(("e", "ed")),
+                _ => None,
+            },
+            Alternation::None => None,
+        }
+    }
+
+    /// Writes the conjunction, changed to fit the word after it.
+    fn write<W: fmt::Write>(self, w: &mut W, conjunction: &str, next: &str) -> fmt::Result {
+        let word = conjunction.trim_end();
+        if let Some((from, to)) = self.change(next)
+            && let Some(head) = word.strip_suffix(from)
+            && (head.is_empty() || head.ends_with(char::is_whitespace))
+        {
+            w.write_str(head)?;
+            w.write_str(to)?;
+            w.write_str(&conjunction[word.len()..])
+        } else {
+            w.write_str(conjunction)
+        }
+    }
 }
@@ -1,23 +1,137 @@
 //! Provides some utility functions for human-readable formatting of words.
+//!
+//! # Features
+//!
+//! - `alloc` (default): Enables the functions that return a `String`. Without it,
+//!   use the `write_*` and `*_into` functions or the `Display` adapters instead.
+//! - `cldr`: Enables `ListJoiner::localized`, which bundles the CLDR list
+//!   patterns of some languages.
 
 #![no_std]
 
+#[cfg(feature = "alloc")]
 extern crate alloc;
 
+mod boundary;
+mod buffer;
 mod case;
+#[cfg(feature = "cldr")]
+mod cldr;
+#[cfg(feature = "alloc")]
+mod ext;
+mod ident;
 mod join;
+mod locale;
 mod name;
+mod unit;
 
+pub use boundary::*;
+pub use buffer::*;
 pub use case::*;
+#[cfg(feature = "cldr")]
+pub use cldr::*;
+#[cfg(feature = "alloc")]
+pub use ext::*;
+pub use ident::*;
 pub use join::*;
+pub use locale::*;
 pub use name::*;
+pub use unit::*;
 
-use alloc::string::String;
+#[cfg(feature = "alloc")]
+use alloc::borrow::Cow;
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
+use core::fmt::{self, Write};
 
-fn fold(mut acc: String, w: &str, ch: char) -> String {
+#[cfg(feature = "alloc")]
+fn fold(mut acc: String, w: &str, sep: &str) -> String {
     if !acc.is_empty() {
-        acc.push(ch);
+        acc.push_str(sep);
     }
     acc.push_str(w);
     acc
 }
+
+/// Compares everything written to it with a string.
+struct Compare<'a> {
+    rest: &'a str,
+}
+
+impl fmt::Write for Compare<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.rest = self.rest.strip_prefix(s).ok_or(fmt::Error)?;
+        Ok(())
+    }
+}
+
+/// Returns `true` if the displayed value is equal to the string, without allocating.
+fn display_eq(t: &str, display: impl fmt::Display) -> bool {
+    let mut compare = Compare { rest: t };
+    fmt::write(&mut compare, format_args!("{display}")).is_ok() && compare.rest.is_empty()
+}
+
+/// Counts the characters written to it.
+struct Count(usize);
+
+impl fmt::Write for Count {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.chars().count();
+        Ok(())
+    }
+}
+
+/// Writes at most `left` characters to the inner writer.
+struct Truncate<W> {
+    w: W,
+    left: usize,
+}
+
+impl<W: fmt::Write> fmt::Write for Truncate<W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = s.char_indices().nth(self.left).map_or(s.len(), |(i, _)| i);
+        self.left -= s[..end].chars().count();
+        self.w.write_str(&s[..end])
+    }
+}
+
+/// Writes the output of `write` to the formatter like [`fmt::Formatter::pad`], with
+/// its width, fill, alignment and precision, without allocating.
+///
+/// The output is written twice when it is padded, first to count its length.
+fn pad(f: &mut fmt::Formatter, write: impl Fn(&mut dyn fmt::Write) -> fmt::Result) -> fmt::Result {
+    if f.width().is_none() && f.precision().is_none() {
+        return write(f);
+    }
+    let mut count = Count(0);
+    write(&mut count)?;
+    let len = f.precision().map_or(count.0, |p| count.0.min(p));
+    let padding = f.width().unwrap_or_default().saturating_sub(len);
+    let (before, after) = match f.align() {
+        Some(fmt::Alignment::Right) => (padding, 0),
+        Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+        _ => (0, padding),
+    };
+    let fill = f.fill();
+    for _ in 0..before {
+        f.write_char(fill)?;
+    }
+    write(&mut Truncate {
+        w: &mut *f,
+        left: len,
+    })?;
+    for _ in 0..after {
+        f.write_char(fill)?;
+    }
+    Ok(())
+}
+
+/// Borrows the string if the displayed value is equal to it.
+#[cfg(feature = "alloc")]
+fn cow(t: &str, display: impl fmt::Display) -> Cow<'_, str> {
+    if display_eq(t, &display) {
+        Cow::Borrowed(t)
+    } else {
+        Cow::Owned(display.to_string())
+    }
+}
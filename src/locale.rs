@@ -0,0 +1,170 @@
+use core::fmt;
+
+/// The language used for the language specific case mappings in Unicode.
+///
+/// Most languages use the same case mappings, which is [`Locale::Root`].
+///
+/// # Examples
+/// ```
+/// use reword::{Case, Locale};
+///
+/// # #[cfg(feature = "alloc")] {
+/// assert_eq!(reword::convert_with_locale("istanbul", Case::ScreamingSnake, Locale::Turkish), "İSTANBUL");
+/// assert_eq!(reword::convert_with_locale("ISPARTA", Case::Snake, Locale::Turkish), "ısparta");
+/// assert_eq!(reword::convert_with_locale("ISPARTA", Case::Snake, Locale::Root), "isparta");
+/// # }
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum Locale {
+    /// The default case mappings.
+    #[default]
+    Root,
+    /// `I` and `ı` are dotless, and `İ` and `i` are dotted, in both cases.
+    Turkish,
+    /// The same mappings as [`Locale::Turkish`].
+    Azerbaijani,
+    /// The dot above `i` and `j` is kept when they are lowercased with an accent
+    /// above, and removed when they are uppercased.
+    Lithuanian,
+}
+
+impl Locale {
+    /// Returns the locale of a language tag, like `tr` or `tr-TR`.
+    ///
+    /// Languages without specific case mappings returns [`Locale::Root`].
+    ///
+    /// # Examples
+    /// ```
+    /// use reword::Locale;
+    ///
+    /// assert_eq!(Locale::from_tag("tr-TR"), Locale::Turkish);
+    /// assert_eq!(Locale::from_tag("LT"), Locale::Lithuanian);
+    /// assert_eq!(Locale::from_tag("nb_NO"), Locale::Root);
+    /// ```
+    pub fn from_tag(tag: &str) -> Locale {
+        let language = tag.split(['-', '_']).next().unwrap_or_default();
+        let is = |code: &str| language.eq_ignore_ascii_case(code);
+        if is("tr") || is("tur") {
+            Locale::Turkish
+        } else if is("az") || is("aze") {
+            Locale::Azerbaijani
+        } else if is("lt") || is("lit") {
+            Locale::Lithuanian
+        } else {
+            Locale::Root
+        }
+    }
+
+    /// Writes the lowercase mapping of `c`, which is between `before` and `after` in a word.
+    pub(crate) fn write_lower<W: fmt::Write>(
+        self,
+        w: &mut W,
+        c: char,
+        before: &str,
+        after: &str,
+    ) -> fmt::Result {
+        let more_above = after.chars().next().is_some_and(is_above);
+        let special = match (self, c) {
+            (_, 'Σ') if is_final_sigma(before, after) => "ς",
+            (Locale::Turkish | Locale::Azerbaijani, 'İ') => "i",
+            (Locale::Turkish | Locale::Azerbaijani, 'I') if after.starts_with('\u{307}') => "i",
+            (Locale::Turkish | Locale::Azerbaijani, 'I') => "ı",
+            (Locale::Turkish | Locale::Azerbaijani, '\u{307}') if before.ends_with('I') => "",
+            (Locale::Lithuanian, 'I') if more_above => "i\u{307}",
+            (Locale::Lithuanian, 'J') if more_above => "j\u{307}",
+            (Locale::Lithuanian, 'Į') if more_above => "į\u{307}",
+            (Locale::Lithuanian, 'Ì') => "i\u{307}\u{300}",
+            (Locale::Lithuanian, 'Í') => "i\u{307}\u{301}",
+            (Locale::Lithuanian, 'Ĩ') => "i\u{307}\u{303}",
+            _ => return c.to_lowercase().try_for_each(|c| w.write_char(c)),
+        };
+        w.write_str(special)
+    }
+
+    /// Writes the uppercase mapping of `c`, which is after `before` in a word.
+    pub(crate) fn write_upper<W: fmt::Write>(
+        self,
+        w: &mut W,
+        c: char,
+        before: &str,
+    ) -> fmt::Result {
+        let after_soft_dotted = before.chars().next_back().is_some_and(is_soft_dotted);
+        match (self, c) {
+            (Locale::Turkish | Locale::Azerbaijani, 'i') => w.write_char('İ'),
+            (Locale::Lithuanian, '\u{307}') if after_soft_dotted => Ok(()),
+            _ => c.to_uppercase().try_for_each(|c| w.write_char(c)),
+        }
+    }
+
+    /// Writes the titlecase mapping of `c`, which is after `before` in a word.
+    ///
+    /// This differs from the uppercase mapping for ligatures and digraphs, where
+    /// only the first letter is uppercased, like `ß` to `Ss`.
+    pub(crate) fn write_title<W: fmt::Write>(
+        self,
+        w: &mut W,
+        c: char,
+        before: &str,
+    ) -> fmt::Result {
+        let title = match c {
+            'ß' => "Ss",
+            'Ǆ' | 'ǅ' | 'ǆ' => "ǅ",
+            'Ǉ' | 'ǈ' | 'ǉ' => "ǈ",
+            'Ǌ' | 'ǋ' | 'ǌ' => "ǋ",
+            'Ǳ' | 'ǲ' | 'ǳ' => "ǲ",
+            'ﬀ' => "Ff",
+            'ﬁ' => "Fi",
+            'ﬂ' => "Fl",
+            'ﬃ' => "Ffi",
+            'ﬄ' => "Ffl",
+            'ﬅ' | 'ﬆ' => "St",
+            'և' => "Եւ",
+            'ᾳ' | 'ᾼ' => "ᾼ",
+            'ῃ' | 'ῌ' => "ῌ",
+            'ῳ' | 'ῼ' => "ῼ",
+            // Greek letters with ypogegrammeni, where the titlecase letter is eight after.
+            '\u{1F80}'..='\u{1FAF}' => {
+                return w.write_char(char::from_u32(c as u32 | 8).unwrap_or(c));
+            }
+            _ => return self.write_upper(w, c, before),
+        };
+        w.write_str(title)
+    }
+}
+
+/// Returns `true` if the character is a combining mark placed above the letter.
+pub(crate) fn is_above(c: char) -> bool {
+    matches!(
+        c,
+        '\u{300}'..='\u{314}'
+            | '\u{33D}'..='\u{344}'
+            | '\u{346}'
+            | '\u{34A}'..='\u{34C}'
+            | '\u{350}'..='\u{352}'
+            | '\u{357}'
+            | '\u{35B}'
+            | '\u{363}'..='\u{36F}'
+    )
+}
+
+/// Returns `true` if the dot of the letter is removed when an accent is placed above it.
+fn is_soft_dotted(c: char) -> bool {
+    matches!(c, 'i' | 'j' | 'į' | 'ɨ' | 'ʝ' | 'і' | 'ј')
+}
+
+/// Returns `true` if the character is ignored when looking for the letters around a sigma.
+fn is_case_ignorable(c: char) -> bool {
+    matches!(
+        c,
+        '\'' | '.' | ':' | '^' | '`' | '·' | '\u{AD}' | '’' | '\u{300}'..='\u{36F}'
+    )
+}
+
+/// Returns `true` if a capital sigma between `before` and `after` ends a word.
+fn is_final_sigma(before: &str, after: &str) -> bool {
+    let is_cased = |c: char| c.is_lowercase() || c.is_uppercase();
+    let prev = before.chars().rev().find(|&c| !is_case_ignorable(c));
+    let next = after.chars().find(|&c| !is_case_ignorable(c));
+    prev.is_some_and(is_cased) && !next.is_some_and(is_cased)
+}
@@ -1,6 +1,13 @@
-use crate::fold;
+use crate::{CapacityError, Locale, pad, write_into};
+#[cfg(feature = "alloc")]
+use crate::{Unit, cow, fold};
+#[cfg(feature = "alloc")]
+use alloc::borrow::Cow;
+#[cfg(feature = "alloc")]
 use alloc::string::String;
+#[cfg(feature = "alloc")]
 use alloc::vec::Vec;
+use core::fmt;
 use unicode_segmentation::UnicodeSegmentation;
 
 /// Formats the input string as a name.
@@ -10,10 +17,64 @@ use unicode_segmentation::UnicodeSegmentation;
 /// let t = "(Even),Olsson&Rogstadkjærnet?";
 /// assert_eq!(reword::name(t), "Even Olsson Rogstadkjærnet");
 /// ```
+#[cfg(feature = "alloc")]
 pub fn name<T: AsRef<str>>(t: T) -> String {
     t.as_ref()
         .unicode_words()
-        .fold(String::new(), |acc, w| fold(acc, w, ' '))
+        .fold(String::new(), |acc, w| fold(acc, w, " "))
+}
+
+/// Formats the string as a name when displayed, without allocating.
+///
+/// # Examples
+/// ```
+/// let t = "(Even),Olsson&Rogstadkjærnet?";
+/// assert_eq!(format!("{}", reword::AsName(t)), "Even Olsson Rogstadkjærnet");
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct AsName<T>(pub T);
+
+impl<T: AsRef<str>> fmt::Display for AsName<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        pad(f, |w| {
+            for (i, word) in self.0.as_ref().unicode_words().enumerate() {
+                if i != 0 {
+                    w.write_str(" ")?;
+                }
+                w.write_str(word)?;
+            }
+            Ok(())
+        })
+    }
+}
+
+/// Formats the input string as a name, borrowing it if it is already a name.
+///
+/// # Examples
+/// ```
+/// use std::borrow::Cow;
+///
+/// assert!(matches!(reword::name_cow("Even Olsson"), Cow::Borrowed("Even Olsson")));
+/// assert_eq!(reword::name_cow("(Even), Olsson"), "Even Olsson");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn name_cow(t: &str) -> Cow<'_, str> {
+    cow(t, AsName(t))
+}
+
+/// Formats the input string as a name into the buffer.
+///
+/// # Examples
+/// ```
+/// let mut buf = [0; 32];
+/// let t = "(Even),Olsson&Rogstadkjærnet?";
+/// assert_eq!(reword::name_into(t, &mut buf), Ok("Even Olsson Rogstadkjærnet"));
+/// assert!(reword::name_into(t, &mut buf[..8]).is_err());
+/// ```
+pub fn name_into<T: AsRef<str>>(t: T, buf: &mut [u8]) -> Result<&str, CapacityError> {
+    write_into(buf, |mut w| {
+        fmt::write(&mut w, format_args!("{}", AsName(t)))
+    })
 }
 
 /// Formats the input string as a name and limits the length of the name.
@@ -24,22 +85,36 @@ pub fn name<T: AsRef<str>>(t: T) -> String {
 /// assert_eq!(reword::name_with_limit(t, 4), "EOR");
 /// assert_eq!(reword::name_with_limit(t, 25), "Even O Rogstadkjærnet");
 /// ```
+#[cfg(feature = "alloc")]
 pub fn name_with_limit<T: AsRef<str>>(t: T, limit: usize) -> String {
     let mut name: Vec<&str> = t.as_ref().unicode_words().collect();
+    let spaces = name.len().saturating_sub(1);
+    if shorten(&mut name, limit, Unit::Graphemes, spaces) {
+        name.join(" ")
+    } else {
+        name.concat()
+    }
+}
+
+/// Shortens the words so they fit within the limit when joined by separators
+/// of the provided total length.
+///
+/// Returns `false` if the words only fit when they are concatenated.
+#[cfg(feature = "alloc")]
+pub(crate) fn shorten(name: &mut Vec<&str>, limit: usize, unit: Unit, spaces: usize) -> bool {
     if name.is_empty() {
-        return String::new();
+        return true;
     }
 
     let len = name.len();
     let mut n = Vec::with_capacity(len);
     let mut sum = 0;
-    for &w in &name {
-        let c = w.graphemes(true).count();
+    for &w in name.iter() {
+        let c = unit.len(w);
         sum += c;
         n.push(c);
     }
 
-    let spaces = len - 1;
     let mut count = sum + spaces;
     let mut zip = name.iter_mut().zip(n);
 
@@ -51,24 +126,25 @@ pub fn name_with_limit<T: AsRef<str>>(t: T, limit: usize) -> String {
         if count <= limit {
             break;
         }
-        count -= c - 1;
         *w = w.graphemes(true).next().unwrap();
+        count -= c - unit.len(w);
     }
 
     // Checks if the first name also needs to be shortened.
-    if let Some((w, c)) = head {
-        if count > limit {
-            count -= c - 1;
-            *w = w.graphemes(true).next().unwrap();
-        }
+    if let Some((w, c)) = head
+        && count > limit
+    {
+        *w = w.graphemes(true).next().unwrap();
+        count -= c - unit.len(w);
     }
 
     if count <= limit {
-        name.join(" ")
-    } else if (count - spaces) <= limit {
-        name.concat()
+        true
     } else {
-        name[..limit].concat()
+        if (count - spaces) > limit {
+            name.truncate(limit);
+        }
+        false
     }
 }
 
@@ -80,12 +156,84 @@ pub fn name_with_limit<T: AsRef<str>>(t: T, limit: usize) -> String {
 /// ```
 /// assert_eq!(reword::username("Even Olsson Rogstadkjærnet"), "evenolssonrogstadkjærnet");
 /// ```
+#[cfg(feature = "alloc")]
 pub fn username<T: AsRef<str>>(t: T) -> String {
-    name(t)
-        .chars()
-        .filter(|c| c.is_alphanumeric())
-        .flat_map(|c| c.to_lowercase())
-        .collect()
+    username_with_locale(t, Locale::Root)
+}
+
+/// Formats the string as a username when displayed, without allocating.
+///
+/// # Examples
+/// ```
+/// assert_eq!(format!("{}", reword::AsUsername("Even Olsson Rogstadkjærnet")), "evenolssonrogstadkjærnet");
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct AsUsername<T>(pub T);
+
+impl<T: AsRef<str>> fmt::Display for AsUsername<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        pad(f, |mut w| {
+            write_username(&mut w, self.0.as_ref(), Locale::Root)
+        })
+    }
+}
+
+fn write_username<W: fmt::Write>(w: &mut W, t: &str, locale: Locale) -> fmt::Result {
+    for word in t.unicode_words() {
+        for (i, c) in word.char_indices() {
+            if c.is_alphanumeric() {
+                locale.write_lower(w, c, &word[..i], &word[i + c.len_utf8()..])?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Creates a username from the provided string, using the case mappings of the locale.
+///
+/// A username can only consist of alphanumeric characters.
+///
+/// # Examples
+/// ```
+/// use reword::Locale;
+///
+/// assert_eq!(reword::username_with_locale("Işıl IŞIK", Locale::Turkish), "ışılışık");
+/// assert_eq!(reword::username_with_locale("Işıl IŞIK", Locale::Root), "işılişik");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn username_with_locale<T: AsRef<str>>(t: T, locale: Locale) -> String {
+    let mut username = String::new();
+    write_username(&mut username, t.as_ref(), locale).expect("writing to a string can not fail");
+    username
+}
+
+/// Creates a username from the provided string, borrowing it if it is already a username.
+///
+/// # Examples
+/// ```
+/// use std::borrow::Cow;
+///
+/// assert!(matches!(reword::username_cow("evenolsson"), Cow::Borrowed("evenolsson")));
+/// assert_eq!(reword::username_cow("Even Olsson"), "evenolsson");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn username_cow(t: &str) -> Cow<'_, str> {
+    cow(t, AsUsername(t))
+}
+
+/// Creates a username from the provided string into the buffer.
+///
+/// A username can only consist of alphanumeric characters.
+///
+/// # Examples
+/// ```
+/// let mut buf = [0; 32];
+/// assert_eq!(reword::username_into("Even Olsson", &mut buf), Ok("evenolsson"));
+/// ```
+pub fn username_into<T: AsRef<str>>(t: T, buf: &mut [u8]) -> Result<&str, CapacityError> {
+    write_into(buf, |mut w| {
+        fmt::write(&mut w, format_args!("{}", AsUsername(t)))
+    })
 }
 
 /// Creates a username from the provided string and limit.
@@ -96,10 +244,7 @@ pub fn username<T: AsRef<str>>(t: T) -> String {
 /// ```
 /// assert_eq!(reword::username_with_limit("Even Olsson Rogstadkjærnet", 25), "evenorogstadkjærnet");
 /// ```
+#[cfg(feature = "alloc")]
 pub fn username_with_limit<T: AsRef<str>>(t: T, limit: usize) -> String {
-    name_with_limit(t, limit)
-        .chars()
-        .filter(|c| c.is_alphanumeric())
-        .flat_map(|c| c.to_lowercase())
-        .collect()
+    username_with_locale(name_with_limit(t, limit), Locale::Root)
 }
@@ -0,0 +1,49 @@
+#[cfg(feature = "alloc")]
+use unicode_segmentation::UnicodeSegmentation;
+
+/// The unit used to measure the length of a name against a limit.
+///
+/// # Examples
+/// ```
+/// use reword::{Case, Unit};
+///
+/// # #[cfg(feature = "alloc")] {
+/// let t = "Even Olsson Rogstadkjærnet";
+/// assert_eq!(reword::convert_with_limit_unit(t, Case::Snake, 21, Unit::Graphemes), "even_o_rogstadkjærnet");
+/// assert_eq!(reword::convert_with_limit_unit(t, Case::Snake, 21, Unit::Bytes), "even_o_r");
+/// # }
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum Unit {
+    /// User-perceived characters, like `é` written as `e` and a combining accent.
+    #[default]
+    Graphemes,
+    /// Unicode scalar values.
+    Chars,
+    /// UTF-8 bytes.
+    Bytes,
+}
+
+impl Unit {
+    /// Returns the length of the string in this unit.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn len(self, s: &str) -> usize {
+        match self {
+            Unit::Graphemes => s.graphemes(true).count(),
+            Unit::Chars => s.chars().count(),
+            Unit::Bytes => s.len(),
+        }
+    }
+
+    /// Returns the longest prefix of whole graphemes that fits within the limit.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn truncate(self, s: &str, limit: usize) -> &str {
+        let mut len = 0;
+        let end = s.grapheme_indices(true).find(|&(_, g)| {
+            len += self.len(g);
+            len > limit
+        });
+        end.map_or(s, |(i, _)| &s[..i])
+    }
+}
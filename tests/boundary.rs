@@ -0,0 +1,124 @@
+#![cfg(feature = "alloc")]
+
+use reword::{Boundary, Case, CaseBuilder};
+
+fn snake(t: &str, boundary: Boundary) -> String {
+    CaseBuilder::from(Case::Snake)
+        .boundary(boundary)
+        .build()
+        .convert(t)
+}
+
+#[test]
+fn boundary() {
+    let t = "helloWorld XMLHttp utf8 a-b a.b a/b (a&b)";
+    assert_eq!(
+        snake(t, Boundary::DEFAULT),
+        "hello_world_xml_http_utf_8_a_b_a_b_a_b_a_b"
+    );
+    assert_eq!(
+        snake(t, Boundary::NONE),
+        "helloworld xmlhttp utf8 a-b a.b a/b (a&b)"
+    );
+    assert_eq!(
+        snake(t, Boundary::SPACE),
+        "helloworld_xmlhttp_utf8_a-b_a.b_a/b_(a&b)"
+    );
+    assert_eq!(
+        snake(t, Boundary::DEFAULT - Boundary::LOWER_UPPER),
+        "helloworld_xml_http_utf_8_a_b_a_b_a_b_a_b"
+    );
+    assert_eq!(
+        snake(t, Boundary::DEFAULT - Boundary::ACRONYM),
+        "hello_world_xmlhttp_utf_8_a_b_a_b_a_b_a_b"
+    );
+    assert_eq!(
+        snake(t, Boundary::DEFAULT - Boundary::DIGIT),
+        "hello_world_xml_http_utf8_a_b_a_b_a_b_a_b"
+    );
+    assert_eq!(
+        snake(t, Boundary::DEFAULT - Boundary::HYPHEN - Boundary::SLASH),
+        "hello_world_xml_http_utf_8_a-b_a_b_a/b_a_b"
+    );
+    assert_eq!(
+        snake(t, Boundary::DEFAULT - Boundary::PUNCTUATION),
+        "hello_world_xml_http_utf_8_a_b_a_b_a_b_(a&b)"
+    );
+    assert_eq!(
+        snake(
+            "version.1.2",
+            Boundary::DEFAULT - Boundary::DOT - Boundary::DIGIT
+        ),
+        "version.1.2"
+    );
+    assert_eq!(
+        snake("a_b", Boundary::DEFAULT - Boundary::UNDERSCORE),
+        "a_b"
+    );
+    assert_eq!(
+        snake(
+            "1920x1080",
+            Boundary::DEFAULT | Boundary::custom(|c| c == 'x')
+        ),
+        "1920_1080"
+    );
+    assert_eq!(snake("", Boundary::DEFAULT), "");
+}
+
+#[test]
+fn boundary_set() {
+    assert!(Boundary::DEFAULT.contains(Boundary::DOT | Boundary::DIGIT));
+    assert!(!(Boundary::DEFAULT - Boundary::DOT).contains(Boundary::DOT));
+    assert_eq!(Boundary::NONE | Boundary::DEFAULT, Boundary::DEFAULT);
+    assert_eq!(Boundary::default(), Boundary::DEFAULT);
+    assert_eq!(
+        format!("{:?}", Boundary::DOT | Boundary::SLASH),
+        r#"{DOT, SLASH}"#
+    );
+}
+
+#[test]
+fn custom_boundary() {
+    let x = Boundary::custom(|c| c == 'x');
+    let at = Boundary::custom(|c| c == '@');
+    assert_eq!(
+        snake("1920x1080@60", Boundary::DIGIT | x | at),
+        "1920_1080_60"
+    );
+    assert_eq!(snake("1920x1080@60", x | at), "1920_1080_60");
+    assert_eq!(snake("ax1080@b", (x | at) - at), "a_1080@b");
+    assert_eq!(snake("ax1080@b", (x | at) - x - at), "ax1080@b");
+    assert_eq!(snake("ax1080@b", (x | at) - Boundary::DIGIT), "a_1080_b");
+
+    assert_ne!(x, at);
+    assert_eq!(x | at, at | x);
+    assert_eq!(x | x, x);
+    assert_eq!((x | at) - at, x);
+    assert_ne!(Boundary::DEFAULT | x, Boundary::DEFAULT);
+    assert_ne!(Boundary::DOT | x, Boundary::SLASH | x);
+    assert_eq!(
+        format!("{:?}", Boundary::DOT | x | at),
+        "{DOT, custom, custom}"
+    );
+
+    let snake = |boundary| CaseBuilder::from(Case::Snake).boundary(boundary).build();
+    assert_ne!(snake(Boundary::DEFAULT | x), snake(Boundary::DEFAULT));
+}
+
+#[test]
+fn custom_boundary_limit() {
+    let x = Boundary::custom(|c| c == 'x');
+    let many = Boundary::DEFAULT | x | x | x | x | x;
+    assert_eq!(many, Boundary::DEFAULT | x);
+
+    let four = Boundary::custom(|c| c == 'a')
+        | Boundary::custom(|c| c == 'b')
+        | Boundary::custom(|c| c == 'c')
+        | Boundary::custom(|c| c == 'd');
+    assert_eq!(four.try_union(four), Some(four));
+    assert_eq!(four.try_union(x), None);
+    assert_eq!(
+        (four - Boundary::NONE).try_union(Boundary::DOT),
+        Some(four | Boundary::DOT)
+    );
+}
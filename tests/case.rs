@@ -1,13 +1,15 @@
+#![cfg(feature = "alloc")]
+
 #[test]
 fn camel_case() {
     assert_eq!(reword::camel_case("hello world"), "helloWorld");
     assert_eq!(reword::camel_case("this_is_a_test"), "thisIsATest");
     assert_eq!(reword::camel_case("  leading_spaces  "), "leadingSpaces");
     assert_eq!(reword::camel_case("trailing_spaces  "), "trailingSpaces");
-    // assert_eq!(
-    //     reword::camel_case("iNpUt_wiTh_MiXeD_cAsE"),
-    //     "inputWithMixedCase"
-    // );
+    assert_eq!(
+        reword::camel_case("iNpUt_wiTh_MiXeD_cAsE"),
+        "inputWithMixedCase"
+    );
     assert_eq!(reword::camel_case("this-is-an_example"), "thisIsAnExample");
     assert_eq!(reword::camel_case("camel+case*input"), "camelCaseInput");
     assert_eq!(
@@ -35,10 +37,10 @@ fn pascal_case() {
     assert_eq!(reword::pascal_case("this_is_a_test"), "ThisIsATest");
     assert_eq!(reword::pascal_case("  leading_spaces  "), "LeadingSpaces");
     assert_eq!(reword::pascal_case("trailing_spaces  "), "TrailingSpaces");
-    // assert_eq!(
-    //     reword::pascal_case("iNpUt_wiTh_MiXeD_cAsE"),
-    //     "InputWithMixedCase"
-    // );
+    assert_eq!(
+        reword::pascal_case("iNpUt_wiTh_MiXeD_cAsE"),
+        "InputWithMixedCase"
+    );
     assert_eq!(reword::pascal_case("this-is-an_example"), "ThisIsAnExample");
     assert_eq!(reword::pascal_case("pascal+case*input"), "PascalCaseInput");
     assert_eq!(
@@ -225,3 +227,666 @@ fn screaming_kebab_case() {
     );
     assert_eq!(reword::screaming_kebab_case("!@#$%^&*()"), "");
 }
+
+#[test]
+fn word_boundaries() {
+    assert_eq!(reword::snake_case("helloWorld"), "hello_world");
+    assert_eq!(reword::snake_case("HelloWorld"), "hello_world");
+    assert_eq!(reword::kebab_case("XMLHttpRequest"), "xml-http-request");
+    assert_eq!(reword::snake_case("getHTTPResponse"), "get_http_response");
+    assert_eq!(reword::snake_case("utf8Encoder"), "utf_8_encoder");
+    assert_eq!(reword::snake_case("userIdList"), "user_id_list");
+    assert_eq!(reword::screaming_snake_case("maxRetries"), "MAX_RETRIES");
+    assert_eq!(reword::screaming_kebab_case("contentType"), "CONTENT-TYPE");
+    assert_eq!(reword::camel_case("user_accountId"), "userAccountId");
+    assert_eq!(reword::pascal_case("XMLHttpRequest"), "XmlHttpRequest");
+    assert_eq!(reword::camel_case("version1.2"), "version1_2");
+    assert_eq!(reword::snake_case("ABC"), "abc");
+    assert_eq!(reword::snake_case("évènementNuméro"), "évènement_numéro");
+    assert_eq!(
+        reword::snake_case_with_limit("evenOlssonRogstadkjærnet", 25),
+        "even_o_rogstadkjærnet"
+    );
+}
+
+#[test]
+fn round_trip() {
+    let converters: [fn(&str) -> String; 6] = [
+        |s| reword::camel_case(s),
+        |s| reword::pascal_case(s),
+        |s| reword::snake_case(s),
+        |s| reword::screaming_snake_case(s),
+        |s| reword::kebab_case(s),
+        |s| reword::screaming_kebab_case(s),
+    ];
+    for input in [
+        "JSON_API_response",
+        "XMLHttpRequest",
+        "string_with_123_numbers",
+    ] {
+        for from in converters {
+            for to in converters {
+                let once = to(&from(input));
+                assert_eq!(to(&from(&once)), once);
+                assert_eq!(once, to(input));
+            }
+        }
+    }
+}
+
+#[test]
+fn case() {
+    use reword::Case;
+
+    for &case in Case::ALL {
+        assert_eq!(case.name().parse(), Ok(case));
+        assert_eq!(case.to_string().parse(), Ok(case));
+        assert_eq!(reword::convert(case.name(), case), case.name());
+    }
+    assert_eq!("snake".parse(), Ok(Case::Snake));
+    assert_eq!("screaming-snake".parse(), Ok(Case::ScreamingSnake));
+    assert_eq!("KebabCase".parse(), Ok(Case::Kebab));
+    assert_eq!("upper_flat".parse(), Ok(Case::UpperFlat));
+    assert_eq!("flat".parse(), Ok(Case::Flat));
+    assert!("unknown".parse::<Case>().is_err());
+    assert_eq!(reword::convert("hello world", Case::Camel), "helloWorld");
+    assert_eq!(
+        reword::convert_with_limit("hello big world", Case::ScreamingKebab, 13),
+        "HELLO-B-WORLD"
+    );
+}
+
+#[test]
+fn detect_case() {
+    use reword::Case;
+
+    assert_eq!(reword::detect_case("user_id"), Some(Case::Snake));
+    assert_eq!(reword::detect_case("USER_ID"), Some(Case::ScreamingSnake));
+    assert_eq!(reword::detect_case("user-id"), Some(Case::Kebab));
+    assert_eq!(reword::detect_case("USER-ID"), Some(Case::ScreamingKebab));
+    assert_eq!(reword::detect_case("userId"), Some(Case::Camel));
+    assert_eq!(reword::detect_case("UserId"), Some(Case::Pascal));
+    assert_eq!(reword::detect_case("User-Id"), Some(Case::Train));
+    assert_eq!(reword::detect_case("user.id"), Some(Case::Dot));
+    assert_eq!(reword::detect_case("user/id"), Some(Case::Path));
+    assert_eq!(reword::detect_case("User_Id"), Some(Case::Ada));
+    assert_eq!(reword::detect_case("user id"), None);
+    assert_eq!(reword::detect_case("user__id"), None);
+    assert_eq!(reword::detect_case("abc"), None);
+    assert_eq!(
+        reword::detect_cases("ABC").collect::<Vec<_>>(),
+        [Case::ScreamingSnake, Case::ScreamingKebab, Case::UpperFlat]
+    );
+    assert_eq!(
+        reword::detect_cases("Abc").collect::<Vec<_>>(),
+        [
+            Case::Pascal,
+            Case::Title,
+            Case::Sentence,
+            Case::Train,
+            Case::Ada
+        ]
+    );
+    assert!(reword::is_case("json_api_response", Case::Snake));
+    assert!(!reword::is_case("JSON_API_response", Case::Snake));
+}
+
+#[test]
+fn title_case() {
+    use reword::TitleStyle;
+
+    assert_eq!(reword::title_case("hello world"), "Hello World");
+    assert_eq!(
+        reword::title_case("the lord of the rings"),
+        "The Lord of the Rings"
+    );
+    assert_eq!(
+        reword::title_case("a tale of two cities"),
+        "A Tale of Two Cities"
+    );
+    assert_eq!(reword::title_case("what is it for"), "What Is It For");
+    assert_eq!(
+        reword::title_case("THE LORD OF THE RINGS"),
+        "The Lord of the Rings"
+    );
+    assert_eq!(
+        reword::title_case("the history of NASA and the ESA"),
+        "The History of NASA and the ESA"
+    );
+    assert_eq!(reword::title_case("json_api_response"), "Json Api Response");
+    assert_eq!(
+        reword::title_case("jumpOverTheFence"),
+        "Jump over the Fence"
+    );
+    assert_eq!(reword::title_case(""), "");
+    assert_eq!(reword::title_case("!@#$%^&*()"), "");
+
+    let t = "walking through the park with a dog";
+    assert_eq!(
+        reword::title_case_with_style(t, TitleStyle::Ap),
+        "Walking Through the Park With a Dog"
+    );
+    assert_eq!(
+        reword::title_case_with_style(t, TitleStyle::Chicago),
+        "Walking through the Park with a Dog"
+    );
+    assert_eq!(
+        reword::title_case_with_style(t, TitleStyle::Apa),
+        "Walking Through the Park With a Dog"
+    );
+    assert_eq!(
+        reword::title_case_with_style(t, TitleStyle::All),
+        "Walking Through The Park With A Dog"
+    );
+    assert_eq!(
+        reword::title_case_with_style("yet another tool so far", TitleStyle::Chicago),
+        "Yet Another Tool So Far"
+    );
+    assert_eq!(
+        reword::title_case_with_style("fast yet simple", TitleStyle::Ap),
+        "Fast yet Simple"
+    );
+    assert_eq!(
+        reword::title_case_with_style("so and yet or", TitleStyle::Chicago),
+        "So and Yet Or"
+    );
+}
+
+#[test]
+fn title_case_acronyms() {
+    use reword::Case;
+
+    assert_eq!(reword::title_case("a NASA"), "A NASA");
+    assert_eq!(reword::title_case("A NASA"), "A NASA");
+    assert!(reword::is_case("A NASA", Case::Title));
+    assert_eq!(reword::detect_case("A NASA"), Some(Case::Title));
+    assert_eq!(reword::title_case("x OF y"), "X of Y");
+    assert_eq!(
+        reword::title_case("A TALE OF TWO CITIES"),
+        "A Tale of Two Cities"
+    );
+    assert_eq!(reword::title_case("NASA ESA"), "Nasa Esa");
+
+    for t in [
+        "a NASA",
+        "a NASA ESA",
+        "the history of NASA and the ESA",
+        "x OF y",
+        "THE LORD OF THE RINGS",
+        "i AM here",
+    ] {
+        let once = reword::title_case(t);
+        assert_eq!(reword::title_case(&once), once);
+        assert!(reword::is_case(&once, Case::Title));
+    }
+}
+
+#[test]
+fn sentence_case() {
+    assert_eq!(reword::sentence_case("hello world"), "Hello world");
+    assert_eq!(reword::sentence_case("user_account_id"), "User account id");
+    assert_eq!(reword::sentence_case("userAccountId"), "User account id");
+    assert_eq!(
+        reword::sentence_case("  leading_spaces  "),
+        "Leading spaces"
+    );
+    assert_eq!(
+        reword::sentence_case("iNpUt_wiTh_MiXeD_cAsE"),
+        "Input with mixed case"
+    );
+    assert_eq!(
+        reword::sentence_case("JSON_API_response"),
+        "Json api response"
+    );
+    assert_eq!(
+        reword::sentence_case("string_with_123_numbers"),
+        "String with 123 numbers"
+    );
+    assert_eq!(reword::sentence_case(""), "");
+    assert_eq!(reword::sentence_case("a"), "A");
+    assert_eq!(reword::sentence_case("!@#$%^&*()"), "");
+}
+
+#[test]
+fn train_case() {
+    assert_eq!(reword::train_case("hello world"), "Hello-World");
+    assert_eq!(reword::train_case("content_type"), "Content-Type");
+    assert_eq!(reword::train_case("XMLHttpRequest"), "Xml-Http-Request");
+    assert_eq!(
+        reword::train_case("iNpUt_wiTh_MiXeD_cAsE"),
+        "Input-With-Mixed-Case"
+    );
+    assert_eq!(reword::train_case(""), "");
+    assert_eq!(reword::train_case("!@#$%^&*()"), "");
+}
+
+#[test]
+fn dot_case() {
+    assert_eq!(reword::dot_case("hello world"), "hello.world");
+    assert_eq!(
+        reword::dot_case("serverMaxConnections"),
+        "server.max.connections"
+    );
+    assert_eq!(reword::dot_case("JSON_API_response"), "json.api.response");
+    assert_eq!(reword::dot_case(""), "");
+}
+
+#[test]
+fn path_case() {
+    assert_eq!(reword::path_case("hello world"), "hello/world");
+    assert_eq!(
+        reword::path_case("crate::case::Convert"),
+        "crate/case/convert"
+    );
+    assert_eq!(
+        reword::path_case("string_with_123_numbers"),
+        "string/with/123/numbers"
+    );
+    assert_eq!(reword::path_case(""), "");
+}
+
+#[test]
+fn flat_case() {
+    assert_eq!(reword::flat_case("hello world"), "helloworld");
+    assert_eq!(reword::flat_case("XMLHttpRequest"), "xmlhttprequest");
+    assert_eq!(reword::upper_flat_case("hello world"), "HELLOWORLD");
+    assert_eq!(reword::upper_flat_case("content-type"), "CONTENTTYPE");
+    assert_eq!(reword::flat_case(""), "");
+    assert_eq!(reword::upper_flat_case("!@#$%^&*()"), "");
+}
+
+#[test]
+fn ada_case() {
+    assert_eq!(reword::ada_case("hello world"), "Hello_World");
+    assert_eq!(reword::ada_case("text_io"), "Text_Io");
+    assert_eq!(reword::ada_case("XMLHttpRequest"), "Xml_Http_Request");
+    assert_eq!(
+        reword::ada_case("iNpUt_wiTh_MiXeD_cAsE"),
+        "Input_With_Mixed_Case"
+    );
+    assert_eq!(
+        reword::ada_case_with_limit("hello big world", 13),
+        "Hello_B_World"
+    );
+    assert_eq!(reword::ada_case(""), "");
+    assert_eq!(reword::ada_case("!@#$%^&*()"), "");
+}
+
+#[test]
+fn cobol_case() {
+    assert_eq!(reword::cobol_case("hello world"), "HELLO-WORLD");
+    assert_eq!(reword::cobol_case("customerRecord"), "CUSTOMER-RECORD");
+    assert_eq!(
+        reword::cobol_case_with_limit("hello big world", 13),
+        "HELLO-B-WORLD"
+    );
+    assert_eq!("COBOL-CASE".parse(), Ok(reword::Case::ScreamingKebab));
+    assert_eq!(reword::cobol_case(""), "");
+}
+
+#[test]
+fn case_builder() {
+    use reword::{CaseBuilder, WordCase};
+
+    let case = CaseBuilder::new()
+        .separator("_")
+        .word_case(WordCase::Capitalized)
+        .first_word_case(WordCase::Upper)
+        .build();
+    assert_eq!(case.convert("snake with caps"), "SNAKE_With_Caps");
+
+    let case = CaseBuilder::new()
+        .separator(".")
+        .word_case(WordCase::Capitalized)
+        .first_word_case(WordCase::Lower)
+        .build();
+    assert_eq!(case.convert("lower_dotted_name"), "lower.Dotted.Name");
+
+    let case = CaseBuilder::new()
+        .separator("::")
+        .word_case(WordCase::Preserve)
+        .build();
+    assert_eq!(case.convert("crate.ioError"), "crate::io::Error");
+
+    let case = CaseBuilder::new().build();
+    assert_eq!(case.convert("Hello World"), "helloworld");
+
+    let case = CaseBuilder::new()
+        .separator(" ")
+        .digit_separator(".")
+        .word_case(WordCase::Upper)
+        .build();
+    assert_eq!(case.convert("version 1 2 beta"), "VERSION 1.2 BETA");
+    assert_eq!(
+        case.convert_with_limit("hello big world", 13),
+        "HELLO B WORLD"
+    );
+    assert_eq!(case.convert(""), "");
+}
+
+#[test]
+fn acronyms() {
+    use reword::{Case, CaseBuilder};
+
+    let convert = |case, t| {
+        CaseBuilder::from(case)
+            .acronyms(reword::ACRONYMS)
+            .build()
+            .convert(t)
+    };
+    assert_eq!(
+        convert(Case::Pascal, "JSON_API_response"),
+        "JSONAPIResponse"
+    );
+    assert_eq!(convert(Case::Pascal, "user_id"), "UserID");
+    assert_eq!(convert(Case::Pascal, "userId"), "UserID");
+    assert_eq!(convert(Case::Pascal, "http_url_parser"), "HTTPURLParser");
+    assert_eq!(convert(Case::Camel, "JSON_API_response"), "jsonAPIResponse");
+    assert_eq!(convert(Case::Camel, "id"), "id");
+    assert_eq!(convert(Case::Snake, "UserID"), "user_id");
+    assert_eq!(convert(Case::Train, "x-request-id"), "X-Request-ID");
+    assert_eq!(convert(Case::Title, "json parser"), "JSON Parser");
+    assert_eq!(convert(Case::Pascal, "idle_idea"), "IdleIdea");
+
+    let case = CaseBuilder::from(Case::Pascal).acronyms(&["Gpu"]).build();
+    assert_eq!(case.convert("gpu_driver"), "GPUDriver");
+}
+
+#[test]
+fn write_case() {
+    use core::fmt;
+    use reword::Case;
+
+    struct Full;
+
+    impl fmt::Write for Full {
+        fn write_str(&mut self, _: &str) -> fmt::Result {
+            Err(fmt::Error)
+        }
+    }
+
+    let t = "the JSON_API response for userId 42";
+    for &case in Case::ALL {
+        let mut s = String::new();
+        reword::write_case(&mut s, t, case).unwrap();
+        assert_eq!(s, reword::convert(t, case));
+
+        let mut s = String::new();
+        reword::write_case_with_limit(&mut s, t, case, 20).unwrap();
+        assert_eq!(s, reword::convert_with_limit(t, case, 20));
+
+        assert!(reword::write_case(&mut Full, t, case).is_err());
+    }
+}
+
+#[test]
+fn as_case() {
+    use reword::{AsCase, Case};
+
+    let t = "the JSON_API response for userId 42";
+    for &case in Case::ALL {
+        assert_eq!(AsCase(t, case).to_string(), reword::convert(t, case));
+    }
+    assert_eq!(
+        format!("let {} = {};", reword::AsSnakeCase("userId"), 1),
+        "let user_id = 1;"
+    );
+    assert_eq!(
+        reword::AsKebabCase(String::from("userId")).to_string(),
+        "user-id"
+    );
+    assert_eq!(reword::AsPascalCase("user id").to_string(), "UserId");
+    assert_eq!(reword::AsTitleCase("the end").to_string(), "The End");
+
+    let snake = reword::AsSnakeCase("userId");
+    assert_eq!(format!("[{snake:>10}]"), "[   user_id]");
+    assert_eq!(format!("[{snake:<10}]"), "[user_id   ]");
+    assert_eq!(format!("[{snake:*^10}]"), "[*user_id**]");
+    assert_eq!(format!("[{snake:.4}]"), "[user]");
+    assert_eq!(format!("[{snake:>6.4}]"), "[  user]");
+    assert_eq!(format!("[{snake:3}]"), "[user_id]");
+    assert_eq!(
+        format!("[{:>7}]", AsCase("ǅungla", Case::UpperFlat)),
+        "[ ǄUNGLA]"
+    );
+    assert_eq!(
+        format!("[{:>8}]", AsCase("ǅungla", Case::Flat)),
+        "[  ǆungla]"
+    );
+    assert_eq!(format!("[{:>8}]", Case::Snake), "[snake_case]");
+    assert_eq!(format!("[{:>11}]", Case::Snake), "[ snake_case]");
+}
+
+#[test]
+fn cow() {
+    use reword::Case;
+    use std::borrow::Cow;
+
+    assert!(matches!(
+        reword::snake_case_cow("user_id"),
+        Cow::Borrowed(_)
+    ));
+    assert!(matches!(reword::snake_case_cow(""), Cow::Borrowed(_)));
+    assert!(matches!(reword::snake_case_cow("user__id"), Cow::Owned(_)));
+    assert!(matches!(reword::snake_case_cow("user_"), Cow::Owned(_)));
+    assert_eq!(reword::snake_case_cow("userId"), "user_id");
+    assert!(matches!(
+        reword::kebab_case_cow("user-id"),
+        Cow::Borrowed(_)
+    ));
+    assert_eq!(reword::kebab_case_cow("user_id"), "user-id");
+    for &case in Case::ALL {
+        let name = reword::convert_cow(case.name(), case);
+        assert!(matches!(name, Cow::Borrowed(_)));
+    }
+}
+
+#[test]
+fn convert_into() {
+    use reword::{Case, CaseBuilder};
+
+    let mut buf = [0; 32];
+    for &case in Case::ALL {
+        let t = "the JSON_API response";
+        assert_eq!(
+            reword::convert_into(t, case, &mut buf).unwrap(),
+            reword::convert(t, case)
+        );
+    }
+    assert_eq!(reword::convert_into("", Case::Snake, &mut []), Ok(""));
+    assert!(reword::convert_into("a", Case::Snake, &mut []).is_err());
+    assert_eq!(
+        reword::convert_into("rogstadkjærnet", Case::ScreamingSnake, &mut buf[..15]),
+        Ok("ROGSTADKJÆRNET")
+    );
+    assert!(reword::convert_into("rogstadkjærnet", Case::Snake, &mut buf[..14]).is_err());
+
+    let case = CaseBuilder::new().separator("::").build();
+    assert_eq!(case.convert_into("a b", &mut buf), Ok("a::b"));
+}
+
+#[test]
+fn locale() {
+    use reword::{Case, Locale};
+
+    let convert = reword::convert_with_locale;
+    assert_eq!(
+        convert("istanbul ili", Case::Pascal, Locale::Turkish),
+        "İstanbulİli"
+    );
+    assert_eq!(
+        convert("DİYARBAKIR_IL", Case::Kebab, Locale::Turkish),
+        "diyarbakır-ıl"
+    );
+    assert_eq!(
+        convert("I\u{307}zmir", Case::Snake, Locale::Azerbaijani),
+        "izmir"
+    );
+    assert_eq!(
+        convert("istanbul", Case::UpperFlat, Locale::Azerbaijani),
+        "İSTANBUL"
+    );
+    assert_eq!(
+        convert("istanbul", Case::UpperFlat, Locale::Lithuanian),
+        "ISTANBUL"
+    );
+    assert_eq!(
+        convert("ÌS", Case::Flat, Locale::Lithuanian),
+        "i\u{307}\u{300}s"
+    );
+    assert_eq!(
+        convert("J\u{303}", Case::Flat, Locale::Lithuanian),
+        "j\u{307}\u{303}"
+    );
+    assert_eq!(convert("Jo", Case::Flat, Locale::Lithuanian), "jo");
+    assert_eq!(
+        convert("i\u{307}\u{300}s", Case::ScreamingSnake, Locale::Lithuanian),
+        "I\u{300}S"
+    );
+    assert_eq!(
+        convert("i\u{307}\u{300}s", Case::Title, Locale::Lithuanian),
+        "I\u{300}s"
+    );
+    assert_eq!(convert("ÌS", Case::Flat, Locale::Root), "ìs");
+}
+
+#[test]
+fn context_mapping() {
+    assert_eq!(reword::snake_case("ΟΔΟΣ ΚΑΛΟΣ"), "οδος_καλος");
+    assert_eq!(reword::camel_case("ΟΔΟΣ_ΚΑΛΟΣ"), "οδοςΚαλος");
+    assert_eq!(reword::snake_case("ΣΑΣ"), "σας");
+    assert_eq!(reword::snake_case("Σ"), "σ");
+    assert_eq!(reword::pascal_case("straße ßeta"), "StraßeSseta");
+    assert_eq!(reword::title_case("ǆungla ﬁsh"), "ǅungla Fish");
+    assert_eq!(reword::screaming_snake_case("straße"), "STRASSE");
+
+    // The limit is checked after the words are uppercased.
+    let t = "ßßßß straße";
+    assert_eq!(reword::screaming_snake_case(t), "SSSSSSSS_STRASSE");
+    assert_eq!(reword::screaming_snake_case_with_limit(t, 10), "SSSSSSSS_S");
+    assert_eq!(reword::pascal_case_with_limit(t, 9), "SsßßßS");
+}
+
+#[test]
+fn limit_unit() {
+    use reword::{Case, CaseBuilder, Unit};
+    use unicode_segmentation::UnicodeSegmentation;
+
+    let inputs = [
+        "Even Olsson Rogstadkjærnet",
+        "ßßßß straße",
+        "version 1 2 3 4",
+        "ΟΔΟΣ ΚΑΛΟΣ",
+        "a\u{301}b\u{301}c\u{301} d\u{301}",
+        "",
+    ];
+    for unit in [Unit::Graphemes, Unit::Chars, Unit::Bytes] {
+        for &case in Case::ALL {
+            for t in inputs {
+                for limit in 0..30 {
+                    let s = reword::convert_with_limit_unit(t, case, limit, unit);
+                    let len = match unit {
+                        Unit::Graphemes => s.graphemes(true).count(),
+                        Unit::Chars => s.chars().count(),
+                        _ => s.len(),
+                    };
+                    assert!(len <= limit, "{t:?} {case:?} {unit:?} {limit}: {s:?}");
+                }
+            }
+        }
+    }
+
+    let case = CaseBuilder::new().separator("::").build();
+    assert_eq!(
+        case.convert_with_limit("hello big world", 11),
+        "hello::b::w"
+    );
+    assert_eq!(
+        reword::camel_case_with_limit("version 1 2", 11),
+        "version1_2"
+    );
+    assert_eq!(
+        reword::camel_case_with_limit("version 1 2", 10),
+        "version1_2"
+    );
+    assert_eq!(reword::camel_case_with_limit("version 1 2", 9), "v1_2");
+    assert_eq!(
+        reword::camel_case_with_limit("Even Olsson Rogstadkjærnet", 24),
+        "evenOlssonRogstadkjærnet"
+    );
+    assert_eq!(
+        reword::camel_case_with_limit("Even Olsson Rogstadkjærnet", 23),
+        "evenORogstadkjærnet"
+    );
+    assert_eq!(reword::screaming_snake_case_with_limit("ß", 1), "S");
+    assert_eq!(
+        reword::convert_with_limit_unit("a\u{301}", Case::Snake, 1, Unit::Chars),
+        ""
+    );
+}
+
+#[test]
+fn mixed_case() {
+    use reword::Case;
+
+    // The casing is random in the whole identifier, but not across whitespace.
+    assert_eq!(reword::snake_case("aToB"), "a_to_b");
+    assert_eq!(reword::pascal_case("xToY"), "XToY");
+    assert_eq!(reword::detect_case("aToB"), Some(Case::Camel));
+    assert_eq!(reword::snake_case("iNpUt"), "input");
+    assert_eq!(reword::snake_case("userId iNpUt"), "user_id_input");
+    assert_eq!(reword::snake_case("MiXeD userId"), "mixed_user_id");
+    assert_eq!(reword::snake_case("MiXeD_userId"), "mixed_userid");
+    assert_eq!(reword::snake_case("aToB_cToD"), "a_to_b_c_to_d");
+    assert_eq!(reword::kebab_case("iNpUtVaLuE getX"), "inputvalue-get-x");
+}
+
+#[test]
+fn acronym_round_trip() {
+    use reword::{Case, CaseBuilder};
+
+    let case = |case| CaseBuilder::from(case).acronyms(reword::ACRONYMS).build();
+    let pascal = case(Case::Pascal);
+    let snake = case(Case::Snake);
+    let camel = case(Case::Camel);
+    assert_eq!(pascal.convert("JSON_API_response"), "JSONAPIResponse");
+    assert_eq!(pascal.convert("JSONAPIResponse"), "JSONAPIResponse");
+    assert_eq!(snake.convert("JSONAPIResponse"), "json_api_response");
+    assert_eq!(snake.convert("HTTPURLParser"), "http_url_parser");
+    assert_eq!(snake.convert("xmlHTTPURL"), "xml_http_url");
+    assert_eq!(snake.convert("HTTPSSL"), "http_ssl");
+    assert_eq!(snake.convert("IDENTITY"), "identity");
+    assert_eq!(reword::snake_case("HTTPURLParser"), "httpurl_parser");
+
+    for input in [
+        "JSON_API_response",
+        "HTTPURLParser",
+        "user_id_list",
+        "XMLHttpRequest",
+    ] {
+        for from in [&pascal, &snake, &camel] {
+            for to in [&pascal, &snake, &camel] {
+                let once = to.convert(from.convert(input));
+                assert_eq!(to.convert(&once), once);
+                assert_eq!(once, to.convert(input));
+            }
+        }
+    }
+}
+
+#[test]
+fn long_acronym_run() {
+    use reword::{Case, CaseBuilder};
+
+    let snake = CaseBuilder::from(Case::Snake)
+        .acronyms(&["A", "AA", "AAA"])
+        .build();
+    let run = "A".repeat(1000);
+    assert_eq!(snake.convert(format!("{run}B")), run.to_lowercase() + "b");
+    assert_eq!(snake.convert("AAAAAAA"), "aaa_aaa_a");
+    assert_eq!(snake.convert("AAAAAAAb"), "aaa_aaa_ab");
+
+    let words = snake.convert(&run);
+    assert_eq!(words.split('_').count(), 334);
+    assert!(words.split('_').all(|w| w == "aaa" || w == "a"));
+}
@@ -0,0 +1,34 @@
+#![cfg(feature = "alloc")]
+
+use reword::{Case, RewordExt, TitleStyle};
+
+#[test]
+fn ext() {
+    const S: &str = "(Even), Olsson&Rogstadkjærnet?";
+    let owned = String::from(S);
+
+    assert_eq!(S.to_name(), reword::name(S));
+    assert_eq!(owned.to_name_with_limit(12), reword::name_with_limit(S, 12));
+    assert_eq!(S.to_username(), reword::username(S));
+    assert_eq!(
+        owned.to_username_with_limit(7),
+        reword::username_with_limit(S, 7)
+    );
+    assert_eq!(S.to_snake_case(), reword::snake_case(S));
+    assert_eq!(
+        owned.to_pascal_case_with_limit(12),
+        reword::pascal_case_with_limit(S, 12)
+    );
+    assert_eq!(S.to_case(Case::Train), reword::train_case(S));
+    assert_eq!(
+        S.to_case_with_limit(Case::Dot, 7),
+        reword::dot_case_with_limit(S, 7)
+    );
+    assert_eq!(
+        "walk with me".to_title_case_with_style(TitleStyle::Ap),
+        "Walk With Me"
+    );
+    assert_eq!(owned.to_cobol_case(), reword::screaming_kebab_case(S));
+    assert!("user_id".is_case(Case::Snake));
+    assert_eq!("user-id".detect_case(), Some(Case::Kebab));
+}
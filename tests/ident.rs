@@ -0,0 +1,78 @@
+#![cfg(feature = "alloc")]
+
+use reword::Language;
+
+#[test]
+fn identifier() {
+    assert_eq!(reword::identifier("user_id", Language::Rust), "user_id");
+    assert_eq!(reword::identifier("type", Language::Rust), "r#type");
+    assert_eq!(reword::identifier("match", Language::Rust), "r#match");
+    assert_eq!(reword::identifier("self", Language::Rust), "self_");
+    assert_eq!(reword::identifier("Self", Language::Rust), "Self_");
+    assert_eq!(reword::identifier("_", Language::Rust), "__");
+    assert_eq!(
+        reword::identifier("1st_place", Language::Rust),
+        "_1st_place"
+    );
+    assert_eq!(reword::identifier("user-id", Language::Rust), "user_id");
+    assert_eq!(reword::identifier("", Language::Rust), "__");
+    assert_eq!(reword::identifier("", Language::Python), "_");
+    assert_eq!(
+        reword::identifier("rogstadkjærnet", Language::Rust),
+        "rogstadkjærnet"
+    );
+
+    assert_eq!(reword::identifier("class", Language::Python), "class_");
+    assert_eq!(reword::identifier("None", Language::Python), "None_");
+    assert_eq!(reword::identifier("match", Language::Python), "match");
+
+    assert_eq!(
+        reword::identifier("delete", Language::JavaScript),
+        "delete_"
+    );
+    assert_eq!(reword::identifier("$el", Language::JavaScript), "$el");
+    assert_eq!(
+        reword::identifier("interface", Language::TypeScript),
+        "interface_"
+    );
+
+    assert_eq!(reword::identifier("type", Language::Go), "type_");
+    assert_eq!(reword::identifier("$el", Language::Go), "_el");
+
+    assert_eq!(reword::identifier("class", Language::Java), "class_");
+    assert_eq!(reword::identifier("_", Language::Java), "__");
+
+    assert_eq!(reword::identifier("int", Language::C), "int_");
+    assert_eq!(
+        reword::identifier("rogstadkjærnet", Language::C),
+        "rogstadkj_rnet"
+    );
+
+    assert_eq!(reword::identifier("select", Language::Sql), "select_");
+    assert_eq!(reword::identifier("ORDER", Language::Sql), "ORDER_");
+    assert_eq!(reword::identifier("orders", Language::Sql), "orders");
+
+    assert_eq!(
+        reword::identifier(reword::camel_case("2 factor auth"), Language::JavaScript),
+        "_2FactorAuth"
+    );
+}
+
+#[test]
+fn unicode_identifier() {
+    assert_eq!(reword::identifier("a½", Language::Rust), "a_");
+    assert_eq!(reword::identifier("x²", Language::Python), "x_");
+    assert_eq!(reword::identifier("a\u{301}", Language::Rust), "a\u{301}");
+    assert_eq!(reword::identifier("\u{301}a", Language::Rust), "_\u{301}a");
+    assert_eq!(reword::identifier("ⅫI", Language::Rust), "ⅫI");
+    assert_eq!(reword::identifier("aⸯ", Language::JavaScript), "a_");
+    assert_eq!(reword::identifier("٣x", Language::TypeScript), "_٣x");
+
+    assert_eq!(reword::identifier("①x", Language::Go), "_x");
+    assert_eq!(reword::identifier("x①", Language::Go), "x_");
+    assert_eq!(reword::identifier("Ⅻ", Language::Go), "_");
+    assert_eq!(reword::identifier("x٣", Language::Go), "x٣");
+    assert_eq!(reword::identifier("٣x", Language::Go), "_٣x");
+    assert_eq!(reword::identifier("x፩", Language::Go), "x_");
+    assert_eq!(reword::identifier("søk", Language::Go), "søk");
+}
@@ -1,3 +1,5 @@
+#![cfg(feature = "alloc")]
+
 #[test]
 fn join() {
     assert_eq!(reword::or_join::<&[&str]>(&[]), "");
@@ -17,3 +19,208 @@ fn join() {
         "a, b, c, d and e"
     );
 }
+
+#[test]
+fn join_into() {
+    let mut buf = [0; 16];
+    assert_eq!(reword::and_join_into::<&[&str]>(&[], &mut buf), Ok(""));
+    assert_eq!(reword::and_join_into(["a"], &mut buf), Ok("a"));
+    assert_eq!(
+        reword::and_join_into(["a", "b", "c"], &mut buf),
+        Ok("a, b and c")
+    );
+    assert_eq!(
+        reword::or_join_into(["a", "b", "c"], &mut buf),
+        Ok("a, b or c")
+    );
+    assert!(reword::or_join_into(["a", "b", "c"], &mut buf[..8]).is_err());
+}
+
+#[test]
+fn join_serial() {
+    assert_eq!(reword::and_join_serial::<&[&str]>(&[]), "");
+    assert_eq!(reword::and_join_serial(["a"]), "a");
+    assert_eq!(reword::and_join_serial(["a", "b"]), "a and b");
+    assert_eq!(reword::and_join_serial(["a", "b", "c"]), "a, b, and c");
+    assert_eq!(
+        reword::and_join_serial(["a", "b", "c", "d"]),
+        "a, b, c, and d"
+    );
+    assert_eq!(reword::or_join_serial(["a", "b"]), "a or b");
+    assert_eq!(reword::or_join_serial(["a", "b", "c"]), "a, b, or c");
+}
+
+#[test]
+fn list_joiner() {
+    use reword::ListJoiner;
+
+    let joiner = ListJoiner::new();
+    assert_eq!(joiner.join::<&[&str]>(&[]), "");
+    assert_eq!(joiner.join(["a"]), "a");
+    assert_eq!(joiner.join(["a", "b", "c"]), "a, b, c");
+    assert_eq!(
+        ListJoiner::AND.join(["a", "b", "c"]),
+        reword::and_join(["a", "b", "c"])
+    );
+    assert_eq!(
+        ListJoiner::OR.serial(true).join(["a", "b", "c"]),
+        "a, b, or c"
+    );
+
+    let nor = ListJoiner::new()
+        .separator("; ")
+        .conjunction(" nor ")
+        .pair_separator(" or ")
+        .serial(true);
+    assert_eq!(nor.join(["a", "b"]), "a or b");
+    assert_eq!(nor.join(["a", "b", "c"]), "a; b; nor c");
+
+    let amp = ListJoiner::AND.conjunction(" & ").serial(true);
+    assert_eq!(amp.join(["a", "b", "c"]), "a, b, & c");
+    assert_eq!(amp.join(["a", "b"]), "a & b");
+
+    let slash = ListJoiner::new().separator(" / ").conjunction(" / ");
+    assert_eq!(slash.join(["a", "b", "c"]), "a / b / c");
+
+    let mut buf = [0; 8];
+    assert_eq!(slash.join_into(["a", "b"], &mut buf), Ok("a / b"));
+    assert!(slash.join_into(["a", "b", "c"], &mut buf).is_err());
+}
+
+#[cfg(feature = "cldr")]
+#[test]
+fn localized() {
+    use reword::{ListJoiner, ListStyle};
+
+    let join = |tag, style, list: &[&str]| ListJoiner::localized(tag, style).unwrap().join(list);
+    assert_eq!(join("en", ListStyle::And, &["a", "b"]), "a and b");
+    assert_eq!(join("en", ListStyle::And, &["a", "b", "c"]), "a, b, and c");
+    assert_eq!(join("en", ListStyle::Unit, &["a", "b", "c"]), "a, b, c");
+    assert_eq!(join("no", ListStyle::And, &["a", "b", "c"]), "a, b og c");
+    assert_eq!(
+        join("nb_NO", ListStyle::Or, &["a", "b", "c"]),
+        "a, b eller c"
+    );
+    assert_eq!(join("nb", ListStyle::Unit, &["a", "b"]), "a, b");
+    assert_eq!(
+        join("de-AT", ListStyle::And, &["a", "b", "c"]),
+        "a, b und c"
+    );
+    assert_eq!(join("FR", ListStyle::Or, &["a", "b", "c"]), "a, b ou c");
+    assert_eq!(join("es", ListStyle::And, &["a", "b", "c"]), "a, b y c");
+    assert_eq!(
+        join("es", ListStyle::And, &["a", "b", "hilo"]),
+        "a, b e hilo"
+    );
+    assert_eq!(
+        join("es-MX", ListStyle::Or, &["siete", "ocho"]),
+        "siete u ocho"
+    );
+    assert_eq!(join("it", ListStyle::And, &["io", "ella"]), "io ed ella");
+    assert_eq!(join("ja", ListStyle::Or, &["a", "b"]), "aまたはb");
+    assert_eq!(join("ja", ListStyle::Or, &["a", "b", "c"]), "a、b、またはc");
+    assert_eq!(join("zh-Hans", ListStyle::And, &["a", "b", "c"]), "a、b和c");
+    assert_eq!(join("zh", ListStyle::Unit, &["a", "b"]), "ab");
+    assert_eq!(ListJoiner::localized("xx", ListStyle::And), None);
+    assert_eq!(ListJoiner::localized("", ListStyle::And), None);
+
+    let limited = |tag, list: &[&str]| {
+        ListJoiner::localized(tag, ListStyle::And)
+            .unwrap()
+            .join_limited(list, 2)
+    };
+    let list = ["a", "b", "c", "d"];
+    assert_eq!(limited("en", &list), "a, b, and 2 more");
+    assert_eq!(limited("nb", &list), "a, b og 2 til");
+    assert_eq!(limited("es", &list), "a, b y 2 más");
+    assert_eq!(limited("ja", &list), "a、b、他2件");
+    assert_eq!(
+        ListJoiner::localized("de", ListStyle::Or)
+            .unwrap()
+            .overflow("{} andere")
+            .join_limited(list, 1),
+        "a oder 3 andere"
+    );
+}
+
+#[test]
+fn alternation() {
+    use reword::{Alternation, ListJoiner};
+
+    let y = ListJoiner::new()
+        .conjunction(" y ")
+        .alternation(Alternation::Spanish);
+    assert_eq!(y.join(["Juan", "Inés"]), "Juan e Inés");
+    assert_eq!(y.join(["madre", "hija"]), "madre e hija");
+    assert_eq!(y.join(["geografía", "Historia"]), "geografía e Historia");
+    assert_eq!(y.join(["cobre", "hierro"]), "cobre y hierro");
+    assert_eq!(y.join(["Juan", "Ana"]), "Juan y Ana");
+    assert_eq!(y.join(["a", "b", "imán"]), "a, b e imán");
+    assert_eq!(y.serial(true).join(["a", "b", "imán"]), "a, b, e imán");
+    assert_eq!(y.pair_separator(" & ").join(["a", "imán"]), "a & imán");
+
+    let o = ListJoiner::new()
+        .conjunction(" o ")
+        .alternation(Alternation::Spanish);
+    assert_eq!(o.join(["mujer", "hombre"]), "mujer u hombre");
+    assert_eq!(o.join(["uno", "otro"]), "uno u otro");
+    assert_eq!(o.join(["Bélgica", "Holanda"]), "Bélgica u Holanda");
+    assert_eq!(o.join(["uno", "dos"]), "uno o dos");
+    assert_eq!(o.join(["uno", "idea"]), "uno o idea");
+
+    let e = ListJoiner::new()
+        .conjunction(" e ")
+        .alternation(Alternation::Italian);
+    assert_eq!(e.join(["Marco", "Elena"]), "Marco ed Elena");
+    assert_eq!(e.join(["Marco", "Anna"]), "Marco e Anna");
+
+    // Only the conjunction is changed.
+    let and = ListJoiner::AND.alternation(Alternation::Spanish);
+    assert_eq!(and.join(["a", "imán"]), "a and imán");
+    assert_eq!(ListJoiner::new().join(["a", "imán"]), "a, imán");
+}
+
+#[test]
+fn join_limited() {
+    use reword::ListJoiner;
+    use std::cell::Cell;
+
+    let list = ["a", "b", "c", "d", "e", "f", "g"];
+    assert_eq!(reword::and_join_limited(list, 3), "a, b, c and 4 more");
+    assert_eq!(
+        reword::and_join_limited(list, 6),
+        "a, b, c, d, e, f and 1 more"
+    );
+    assert_eq!(reword::and_join_limited(list, 7), "a, b, c, d, e, f and g");
+    assert_eq!(
+        reword::and_join_limited(list, usize::MAX),
+        reword::and_join(list)
+    );
+    assert_eq!(reword::and_join_limited(list, 1), "a and 6 more");
+    assert_eq!(reword::and_join_limited(list, 0), "7 more");
+    assert_eq!(reword::and_join_limited::<&[&str]>(&[], 0), "");
+    assert_eq!(reword::or_join_limited(list, 2), "a, b or 5 more");
+
+    let joiner = ListJoiner::AND.serial(true).overflow("{} other fields");
+    assert_eq!(joiner.join_limited(list, 2), "a, b, and 5 other fields");
+    assert_eq!(joiner.join_limited(list, 1), "a and 6 other fields");
+    let joiner = ListJoiner::AND.overflow("more");
+    assert_eq!(joiner.join_limited(list, 2), "a, b and more");
+
+    // The elements that are left out are only counted.
+    struct Field<'a>(&'a Cell<usize>);
+    impl AsRef<str> for Field<'_> {
+        fn as_ref(&self) -> &str {
+            self.0.set(self.0.get() + 1);
+            "field"
+        }
+    }
+    let read = Cell::new(0);
+    let fields = (0..1_000_000).map(|_| Field(&read));
+    let mut buf = [0; 32];
+    assert_eq!(
+        ListJoiner::AND.join_limited_into(fields, 2, &mut buf),
+        Ok("field, field and 999998 more")
+    );
+    assert_eq!(read.get(), 2);
+}
@@ -1,3 +1,5 @@
+#![cfg(feature = "alloc")]
+
 const S: &str = "(Even), Olsson&Rogstadkjærnet?";
 
 #[test]
@@ -23,3 +25,82 @@ fn username() {
     assert_eq!(reword::username_with_limit(S, 1), "e");
     assert_eq!(reword::username_with_limit(S, 0), "");
 }
+
+#[test]
+fn as_name() {
+    assert_eq!(format!("{}", reword::AsName(S)), reword::name(S));
+    assert_eq!(format!("{}", reword::AsName("")), "");
+    assert_eq!(format!("{}", reword::AsUsername(S)), reword::username(S));
+    assert_eq!(format!("<{}>", reword::AsUsername("!?")), "<>");
+    assert_eq!(
+        format!("[{:>12}]", reword::AsName("even olsson")),
+        "[ even olsson]"
+    );
+    assert_eq!(format!("[{:-<6}]", reword::AsUsername("Ola")), "[ola---]");
+    assert_eq!(format!("[{:.2}]", reword::AsUsername("Ola")), "[ol]");
+}
+
+#[test]
+fn cow() {
+    use std::borrow::Cow;
+
+    assert!(matches!(reword::name_cow("Even Olsson"), Cow::Borrowed(_)));
+    assert!(matches!(reword::name_cow(""), Cow::Borrowed(_)));
+    assert!(matches!(reword::name_cow("Even  Olsson"), Cow::Owned(_)));
+    assert_eq!(reword::name_cow(S), reword::name(S));
+    assert!(matches!(
+        reword::username_cow("evenolsson"),
+        Cow::Borrowed(_)
+    ));
+    assert!(matches!(reword::username_cow("EvenOlsson"), Cow::Owned(_)));
+    assert_eq!(reword::username_cow(S), reword::username(S));
+}
+
+#[test]
+fn name_into() {
+    let mut buf = [0; 32];
+    assert_eq!(
+        reword::name_into(S, &mut buf),
+        Ok("Even Olsson Rogstadkjærnet")
+    );
+    assert!(reword::name_into(S, &mut buf[..26]).is_err());
+    assert_eq!(
+        reword::username_into(S, &mut buf),
+        Ok("evenolssonrogstadkjærnet")
+    );
+    assert!(reword::username_into(S, &mut buf[..1]).is_err());
+    assert_eq!(reword::username_into("", &mut []), Ok(""));
+}
+
+#[test]
+fn username_with_locale() {
+    use reword::Locale;
+
+    assert_eq!(
+        reword::username_with_locale("IŞIK İlhan", Locale::Turkish),
+        "ışıkilhan"
+    );
+    assert_eq!(
+        reword::username_with_locale(S, Locale::Turkish),
+        reword::username(S)
+    );
+    assert_eq!(
+        reword::username_with_locale(S, Locale::Root),
+        reword::username(S)
+    );
+}
+
+#[test]
+fn username_final_sigma() {
+    let t = "ΟΔΟΣ ΚΑΛΟΣ";
+    assert_eq!(reword::username(t), "οδοςκαλος");
+    assert_eq!(reword::username_cow(t), reword::username(t));
+    assert_eq!(format!("{}", reword::AsUsername(t)), reword::username(t));
+    let mut buf = [0; 32];
+    assert_eq!(
+        reword::username_into(t, &mut buf),
+        Ok(reword::username(t).as_str())
+    );
+    assert_eq!(reword::username_with_limit(t, 10), "οδοςκαλος");
+    assert_eq!(reword::username_with_limit("ΟΔΟΣ ΚΑΛΟΣ ΑΣ", 9), "οδοςκας");
+}